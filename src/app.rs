@@ -1,53 +1,113 @@
 use std::vec;
 
 use swc_core::{
-    common::{util::take::Take, DUMMY_SP},
+    common::{comments::Comments, util::take::Take, Spanned, DUMMY_SP},
     ecma::{
         ast::*,
-        utils::{is_valid_ident, prepend_stmts},
+        utils::{is_valid_ident, prepend_stmts, undefined},
         visit::*,
     },
 };
 
-use crate::Config;
+use crate::{
+    utils::{emit_error, emit_note, excluded_arg, file_pragma_skip},
+    Config, ResolvedFile,
+};
 
-static DIRECTIVE: &str = "data-superjson";
 static SERIALIZER_FUNCTION: &str = "serialize";
 static DESERIALIZER_COMPONENT: &str = "SuperJSONComponent";
 static DESERIALIZER_PROPS_ATTR: &str = "props";
 static DESERIALIZER_PROPS_COMPONENT: &str = "component";
-static TOOLS_SRC: &str = "next-superjson-plugin/tools";
-static CLIENT_SRC: &str = "next-superjson-plugin/client";
+static DESERIALIZER_DISPLAY_NAME: &str = "displayName";
+
+struct AppTransformer<C: Comments> {
+    comments: C,
+    require_pragma: bool,
+
+    directive: String,
+    excluded: Vec<String>,
+    tools_module: String,
+    client_module: String,
+    explain: bool,
 
-struct AppTransformer {
-    transformed: bool,
+    transformed: usize,
 }
 
-pub fn transform_app(_: Config) -> impl VisitMut {
-    AppTransformer { transformed: false }
+pub fn transform_app<C: Comments>(
+    config: Config,
+    file: ResolvedFile,
+    comments: C,
+) -> impl VisitMut {
+    AppTransformer {
+        comments,
+        require_pragma: config.require_pragma,
+
+        directive: config.app.directive,
+        excluded: file.excluded,
+        tools_module: config.tools_module,
+        client_module: config.client_module,
+        explain: config.explain,
+
+        transformed: 0,
+    }
+}
+
+impl<C: Comments> AppTransformer<C> {
+    fn serialize_args(&self, props: Vec<PropOrSpread>) -> Vec<ExprOrSpread> {
+        let mut args = vec![Expr::Object(ObjectLit {
+            span: DUMMY_SP,
+            props,
+        })
+        .into()];
+
+        if !self.excluded.is_empty() {
+            args.push(excluded_arg(&self.excluded));
+        }
+
+        args
+    }
 }
 
 trait JSXUtil {
-    fn as_expr(&self) -> Expr;
+    fn as_expr(&self) -> Option<Expr>;
+    // <UI.Chart /> => UI.Chart
+    fn to_name(&self) -> String;
 }
 
 impl JSXUtil for JSXMemberExpr {
-    fn as_expr(&self) -> Expr {
-        match &self.obj {
+    fn to_name(&self) -> String {
+        let obj = match &self.obj {
+            JSXObject::Ident(id) => id.sym.to_string(),
+            JSXObject::JSXMemberExpr(member) => member.to_name(),
+        };
+
+        format!("{obj}.{}", self.prop.sym)
+    }
+
+    fn as_expr(&self) -> Option<Expr> {
+        Some(match &self.obj {
             JSXObject::Ident(id) => id.clone().into(),
             JSXObject::JSXMemberExpr(member) => MemberExpr {
-                obj: Box::new(member.as_expr()),
+                obj: Box::new(member.as_expr()?),
                 prop: MemberProp::Ident(member.prop.clone()),
                 span: DUMMY_SP,
             }
             .into(),
-        }
+        })
     }
 }
 
 impl JSXUtil for JSXElementName {
-    fn as_expr(&self) -> Expr {
+    fn to_name(&self) -> String {
         match self {
+            JSXElementName::Ident(id) => id.sym.to_string(),
+            JSXElementName::JSXMemberExpr(member) => member.to_name(),
+            JSXElementName::JSXNamespacedName(name) => format!("{}:{}", name.ns.sym, name.name.sym),
+        }
+    }
+
+    fn as_expr(&self) -> Option<Expr> {
+        Some(match self {
             JSXElementName::Ident(id) => {
                 if is_valid_ident(&id.sym) {
                     id.clone().into()
@@ -56,22 +116,51 @@ impl JSXUtil for JSXElementName {
                 }
             }
             JSXElementName::JSXMemberExpr(member) => MemberExpr {
-                obj: Box::new(member.as_expr()),
+                obj: Box::new(member.as_expr()?),
                 prop: member.prop.clone().into(),
                 span: DUMMY_SP,
             }
             .into(),
             // namespace cannot be component
-            _ => unreachable!(),
-        }
+            JSXElementName::JSXNamespacedName(_) => return None,
+        })
     }
 }
 
-impl VisitMut for AppTransformer {
+impl<C: Comments> VisitMut for AppTransformer<C> {
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        let skipped = file_pragma_skip(
+            &self.comments,
+            module.body.first().map_or(module.span.lo, Spanned::span_lo),
+            module.body.iter().map(ModuleItem::as_stmt),
+            self.require_pragma,
+        );
+
+        if skipped.is_none() {
+            module.visit_mut_children_with(self);
+        }
+
+        if self.explain {
+            let outcome = match (skipped, self.transformed) {
+                (Some(reason), _) => format!("not transformed: {reason}"),
+                (None, 0) => format!("not transformed: no element with `{}`", self.directive),
+                (None, n) => format!(
+                    "serialized the props of {n} element(s) with `{}`",
+                    self.directive
+                ),
+            };
+
+            emit_note(
+                module.span.shrink_to_lo(),
+                &format!("app router, {outcome}"),
+            );
+        }
+    }
+
     fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
         items.visit_mut_children_with(self);
 
-        if self.transformed {
+        if self.transformed > 0 {
             // add import decl
 
             prepend_stmts(
@@ -85,7 +174,7 @@ impl VisitMut for AppTransformer {
                             is_type_only: false,
                         }
                         .into()],
-                        src: Box::new(TOOLS_SRC.into()),
+                        src: Box::new(self.tools_module.as_str().into()),
                         ..ImportDecl::dummy()
                     })),
                     ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
@@ -94,7 +183,7 @@ impl VisitMut for AppTransformer {
                             span: DUMMY_SP,
                         }
                         .into()],
-                        src: Box::new(CLIENT_SRC.into()),
+                        src: Box::new(self.client_module.as_str().into()),
                         ..ImportDecl::dummy()
                     })),
                 ]
@@ -106,24 +195,56 @@ impl VisitMut for AppTransformer {
     fn visit_mut_jsx_element(&mut self, elem: &mut JSXElement) {
         elem.visit_mut_children_with(self);
 
-        let mut found = false;
+        let is_directive = |attr_or_spread: &JSXAttrOrSpread| {
+            matches!(
+                attr_or_spread,
+                JSXAttrOrSpread::JSXAttr(JSXAttr {
+                    name: JSXAttrName::Ident(id),
+                    ..
+                }) if *id.sym == self.directive
+            )
+        };
 
-        // find and remove data-superjson directive
-        elem.opening.attrs.retain(|attr_or_spread| {
-            if let JSXAttrOrSpread::JSXAttr(JSXAttr {
-                name: JSXAttrName::Ident(id),
-                ..
-            }) = attr_or_spread
+        // find data-superjson directive, left in place on errors
+        if elem.opening.attrs.iter().any(is_directive) {
+            // namespace cannot be component
+            let Some(component) = elem.opening.name.as_expr() else {
+                emit_error(
+                    elem.opening.name.span(),
+                    &format!("`{}` cannot be used on a namespaced element", self.directive),
+                );
+                return;
+            };
+
+            if let Some(attr) =
+                elem.opening
+                    .attrs
+                    .iter()
+                    .find_map(|attr_or_spread| match attr_or_spread {
+                        JSXAttrOrSpread::JSXAttr(
+                            attr @ JSXAttr {
+                                value:
+                                    Some(JSXAttrValue::JSXExprContainer(JSXExprContainer {
+                                        expr: JSXExpr::JSXEmptyExpr(_),
+                                        ..
+                                    })),
+                                ..
+                            },
+                        ) => Some(attr),
+                        _ => None,
+                    })
             {
-                if &*id.sym == DIRECTIVE {
-                    found = true;
-                    return false;
-                }
+                emit_error(
+                    attr.span,
+                    &format!("empty attribute value on a `{}` element", self.directive),
+                );
+                return;
             }
-            true
-        });
 
-        if found {
+            elem.opening
+                .attrs
+                .retain(|attr_or_spread| !is_directive(attr_or_spread));
+
             // attrs -> obj props
             let list: Vec<PropOrSpread> = elem
                 .opening
@@ -155,7 +276,8 @@ impl VisitMut for AppTransformer {
                                 value: true,
                                 span: DUMMY_SP,
                             }))),
-                            _ => unreachable!(),
+                            // empty expressions are rejected above
+                            _ => undefined(DUMMY_SP),
                         };
 
                         Box::new(Prop::KeyValue(KeyValueProp { key, value })).into()
@@ -176,11 +298,7 @@ impl VisitMut for AppTransformer {
                     value: Some(
                         JSXExprContainer {
                             expr: Box::new(Expr::Call(CallExpr {
-                                args: vec![Expr::Object(ObjectLit {
-                                    span: DUMMY_SP,
-                                    props: list,
-                                })
-                                .into()],
+                                args: self.serialize_args(list),
                                 callee: Box::new(Expr::Ident(Ident::new(
                                     SERIALIZER_FUNCTION.into(),
                                     DUMMY_SP,
@@ -201,13 +319,22 @@ impl VisitMut for AppTransformer {
                     span: DUMMY_SP,
                     value: Some(
                         JSXExprContainer {
-                            expr: Box::new(elem.opening.name.as_expr()).into(),
+                            expr: Box::new(component).into(),
                             span: DUMMY_SP,
                         }
                         .into(),
                     ),
                 }
                 .into(),
+                // SuperJSON(Chart) in React DevTools
+                JSXAttr {
+                    name: Ident::new(DESERIALIZER_DISPLAY_NAME.into(), DUMMY_SP).into(),
+                    span: DUMMY_SP,
+                    value: Some(JSXAttrValue::Lit(Lit::Str(
+                        format!("SuperJSON({})", elem.opening.name.to_name()).into(),
+                    ))),
+                }
+                .into(),
             ];
 
             // change element name
@@ -217,7 +344,7 @@ impl VisitMut for AppTransformer {
                 closing.name = Ident::new(DESERIALIZER_COMPONENT.into(), DUMMY_SP).into();
             }
 
-            self.transformed = true;
+            self.transformed += 1;
         }
     }
 }
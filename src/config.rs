@@ -0,0 +1,537 @@
+use std::{
+    fmt,
+    path::{Component, Path},
+};
+
+use glob::{MatchOptions, Pattern};
+use serde::{
+    de::{self, MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::schema::unknown_options;
+
+// `*` stays within a single path component, `**` crosses them
+static GLOB_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+// app/blog/page.tsx -> /blog
+static APP_ROUTE_FILES: &[&str] = &[
+    "page",
+    "layout",
+    "template",
+    "loading",
+    "error",
+    "not-found",
+    "default",
+];
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct Config {
+    pub mode: Mode,
+    /// Taken from the host when unset
+    pub env: Option<Env>,
+    /// Reports why each file was or wasn't transformed
+    pub explain: bool,
+    /// Only transforms files with `"use superjson"` or `/* @superjson */`
+    pub require_pragma: bool,
+    /// How a page with conflicting data fetching exports is reported
+    pub conflicting_exports: Severity,
+
+    /// Shorthand for `pages.excluded`
+    pub excluded: Vec<String>,
+
+    pub pages: PagesConfig,
+    pub app: AppConfig,
+
+    /// Roots of the Pages Router, relative to the project directory
+    pub pages_dirs: Vec<String>,
+    /// Next.js `pageExtensions`, telling pages apart from colocated files
+    pub page_extensions: Vec<String>,
+    /// Roots of the App Router, relative to the project directory
+    pub app_dirs: Vec<String>,
+    /// Transform used for files outside both roots, left alone when unset
+    pub fallback: Option<DirType>,
+
+    /// Packages under node_modules that are transformed, like Next.js `transpilePackages`
+    pub transpile_packages: Vec<String>,
+
+    /// Globs of files to transform, all files when empty
+    #[serde(
+        deserialize_with = "deserialize_patterns",
+        serialize_with = "serialize_patterns"
+    )]
+    pub include: Vec<Pattern>,
+    /// Globs of files to leave alone, taking precedence over `include`
+    #[serde(
+        deserialize_with = "deserialize_patterns",
+        serialize_with = "serialize_patterns"
+    )]
+    pub ignore: Vec<Pattern>,
+
+    /// Overrides keyed by route globs, applied in declaration order
+    #[serde(
+        deserialize_with = "deserialize_routes",
+        serialize_with = "serialize_routes"
+    )]
+    pub routes: Vec<(Pattern, RouteConfig)>,
+
+    /// Module the generated server-side helpers are imported from
+    pub tools_module: String,
+    /// Module the generated `SuperJSONComponent` is imported from
+    pub client_module: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct PagesConfig {
+    pub enabled: bool,
+    /// Props passed through data fetching functions without serialization
+    pub excluded: Vec<String>,
+}
+
+impl Default for PagesConfig {
+    fn default() -> Self {
+        PagesConfig {
+            enabled: true,
+            excluded: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct AppConfig {
+    pub enabled: bool,
+    /// JSX attribute marking elements whose props are serialized
+    pub directive: String,
+    /// Props passed to client components without serialization
+    pub excluded: Vec<String>,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        AppConfig {
+            enabled: true,
+            directive: "data-superjson".into(),
+            excluded: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct RouteConfig {
+    pub enabled: Option<bool>,
+    pub excluded: Option<Vec<String>>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            mode: Mode::Auto,
+            env: None,
+            explain: false,
+            require_pragma: false,
+            conflicting_exports: Severity::Error,
+            excluded: vec![],
+            pages: Default::default(),
+            app: Default::default(),
+            pages_dirs: vec!["pages".into(), "src/pages".into()],
+            page_extensions: vec!["tsx".into(), "ts".into(), "jsx".into(), "js".into()],
+            app_dirs: vec!["app".into(), "src/app".into()],
+            fallback: None,
+            transpile_packages: vec![],
+            include: vec![],
+            ignore: vec![],
+            routes: vec![],
+            tools_module: "next-superjson-plugin/tools".into(),
+            client_module: "next-superjson-plugin/client".into(),
+        }
+    }
+}
+
+impl Config {
+    /// Parses the options given in next.config.js, reporting every unknown key
+    pub fn parse(raw: &str) -> Result<Config, Vec<String>> {
+        let options = serde_json::from_str(raw).map_err(|err| vec![err.to_string()])?;
+
+        let unknown = unknown_options(&options);
+        if !unknown.is_empty() {
+            return Err(unknown);
+        }
+
+        // from the source again, the parsed value doesn't keep the order of `routes`
+        serde_json::from_str(raw).map_err(|err| vec![err.to_string()])
+    }
+
+    /// Decides which transform applies to a file and the options of its route
+    ///
+    /// Hosts like @swc/jest may not know the file, which only the forced modes and
+    /// `fallback` can handle.
+    pub fn resolve(&self, cwd: Option<&str>, path: Option<&str>) -> Option<ResolvedFile> {
+        self.resolve_or_skip(cwd, path).ok()
+    }
+
+    /// Same as `resolve`, with the reason a file is skipped
+    pub fn resolve_or_skip(
+        &self,
+        cwd: Option<&str>,
+        path: Option<&str>,
+    ) -> Result<ResolvedFile, String> {
+        let forced = match self.mode {
+            Mode::Auto => None,
+            Mode::Page => Some(DirType::Page),
+            Mode::App => Some(DirType::App),
+            Mode::Off => return Err("`mode` is off".into()),
+        };
+
+        let relative_path = cwd.zip(path).map(|(cwd, path)| relative_path(cwd, path));
+
+        let (dir_type, route) = match relative_path {
+            Some(Some(relative_path)) => {
+                if !self.is_included(&relative_path) {
+                    return Err("filtered out by `include` / `ignore`".into());
+                }
+
+                // skip non-source stuff
+                if let Some(package) = package_name(&relative_path) {
+                    if !self.transpile_packages.iter().any(|name| *name == package) {
+                        return Err(format!(
+                            "package `{package}` is not listed in `transpilePackages`"
+                        ));
+                    }
+
+                    // packages cannot define routes, only components
+                    (forced.unwrap_or(DirType::App), None)
+                } else {
+                    (
+                        forced
+                            .or_else(|| self.dir_type(&relative_path))
+                            .ok_or("not a page or app router file")?,
+                        self.route(&relative_path),
+                    )
+                }
+            }
+            // outside both roots
+            Some(None) => (
+                forced
+                    .or_else(|| self.fallback.clone())
+                    .ok_or("outside the project directory")?,
+                None,
+            ),
+            None => (
+                forced
+                    .or_else(|| self.fallback.clone())
+                    .ok_or("the host did not provide the file path and `fallback` is not set")?,
+                None,
+            ),
+        };
+
+        self.resolve_route(dir_type, route)
+    }
+
+    /// Options of a file handled by the `dir_type` transform, narrowed to its route
+    pub fn resolve_route(
+        &self,
+        dir_type: DirType,
+        route: Option<String>,
+    ) -> Result<ResolvedFile, String> {
+        let route_config = route
+            .as_ref()
+            .map(|route| self.route_config(route))
+            .unwrap_or_default();
+
+        let (enabled, section, excluded) = match dir_type {
+            DirType::Page => (
+                self.pages.enabled,
+                "pages",
+                [&self.excluded[..], &self.pages.excluded[..]].concat(),
+            ),
+            DirType::App => (self.app.enabled, "app", self.app.excluded.clone()),
+        };
+
+        match route_config.enabled {
+            Some(false) => {
+                return Err(format!(
+                    "disabled for route `{}` by `routes`",
+                    route.as_deref().unwrap_or_default()
+                ))
+            }
+            None if !enabled => return Err(format!("`{section}.enabled` is false")),
+            _ => {}
+        }
+
+        Ok(ResolvedFile {
+            dir_type,
+            route,
+            excluded: route_config.excluded.unwrap_or(excluded),
+        })
+    }
+
+    pub fn is_included(&self, relative_path: &str) -> bool {
+        let path = relative_path.trim_start_matches('/');
+
+        let matches = |patterns: &[Pattern]| {
+            patterns
+                .iter()
+                .any(|pattern| pattern.matches_with(path, GLOB_OPTIONS))
+        };
+
+        (self.include.is_empty() || matches(&self.include)) && !matches(&self.ignore)
+    }
+
+    pub fn dir_type(&self, relative_path: &str) -> Option<DirType> {
+        let path = Path::new(relative_path.trim_start_matches('/'));
+
+        match self.root(path) {
+            // colocated components, hooks and tests are not pages
+            Some((DirType::Page, _)) if self.page_extension(path).is_none() => None,
+            Some((dir_type, _)) => Some(dir_type),
+            None => self.fallback.clone(),
+        }
+    }
+
+    /// Route of a file under the pages or app root
+    ///
+    /// pages/blog/index.tsx -> /blog
+    /// app/(marketing)/about/page.tsx -> /about
+    pub fn route(&self, relative_path: &str) -> Option<String> {
+        let path = Path::new(relative_path.trim_start_matches('/'));
+        let (dir_type, root) = self.root(path)?;
+
+        let relative = path.strip_prefix(root).ok()?;
+        let relative = match (&dir_type, self.page_extension(path)) {
+            // [id].page.tsx -> [id]
+            (DirType::Page, Some(ext)) => {
+                let relative = relative.to_string_lossy();
+                Path::new(&relative[..relative.len() - ext.len() - 1]).to_path_buf()
+            }
+            _ => relative.with_extension(""),
+        };
+        let mut segments: Vec<_> = relative.iter().map(|s| s.to_string_lossy()).collect();
+
+        match dir_type {
+            DirType::Page => {
+                if segments.last().map_or(false, |s| s == "index") {
+                    segments.pop();
+                }
+            }
+            DirType::App => {
+                // route groups and parallel route slots are not part of the URL
+                segments
+                    .retain(|s| !(s.starts_with('(') && s.ends_with(')') || s.starts_with('@')));
+
+                if segments
+                    .last()
+                    .map_or(false, |s| APP_ROUTE_FILES.contains(&&**s))
+                {
+                    segments.pop();
+                }
+            }
+        }
+
+        Some(format!("/{}", segments.join("/")))
+    }
+
+    /// Merges the overrides of every pattern matching `route`, later ones winning
+    pub fn route_config(&self, route: &str) -> RouteConfig {
+        self.routes
+            .iter()
+            .filter(|(pattern, _)| {
+                // /admin/** covers /admin itself
+                pattern.matches_with(route, GLOB_OPTIONS)
+                    || pattern.as_str().strip_suffix("/**") == Some(route)
+            })
+            .fold(RouteConfig::default(), |merged, (_, route_config)| {
+                RouteConfig {
+                    enabled: route_config.enabled.or(merged.enabled),
+                    excluded: route_config.excluded.clone().or(merged.excluded),
+                }
+            })
+    }
+
+    // longest configured extension, so `page.tsx` beats `tsx`
+    fn page_extension(&self, path: &Path) -> Option<&str> {
+        let file_name = path.file_name()?.to_str()?;
+
+        self.page_extensions
+            .iter()
+            .map(|ext| ext.trim_start_matches('.'))
+            .filter(|ext| {
+                file_name
+                    .strip_suffix(ext)
+                    .and_then(|stem| stem.strip_suffix('.'))
+                    .map_or(false, |stem| !stem.is_empty())
+            })
+            .max_by_key(|ext| ext.len())
+    }
+
+    // deepest root wins when roots are nested
+    fn root<'a>(&'a self, path: &Path) -> Option<(DirType, &'a Path)> {
+        let deepest = |roots: &'a [String]| {
+            roots
+                .iter()
+                .map(|root| Path::new(root.trim_matches('/')))
+                .filter(|root| path.starts_with(root))
+                .max_by_key(|root| root.components().count())
+        };
+
+        match (deepest(&self.pages_dirs), deepest(&self.app_dirs)) {
+            (Some(page), Some(app)) if app.components().count() > page.components().count() => {
+                Some((DirType::App, app))
+            }
+            (Some(page), _) => Some((DirType::Page, page)),
+            (None, Some(app)) => Some((DirType::App, app)),
+            (None, None) => None,
+        }
+    }
+}
+
+fn deserialize_patterns<'de, D>(deserializer: D) -> Result<Vec<Pattern>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|pattern| Pattern::new(pattern).map_err(de::Error::custom))
+        .collect()
+}
+
+fn serialize_patterns<S: Serializer>(
+    patterns: &[Pattern],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(patterns.iter().map(Pattern::as_str))
+}
+
+// None for files outside the project directory
+fn relative_path(cwd: &str, path: &str) -> Option<String> {
+    // Windows path separator -> Unix path separator
+    let cwd = cwd.replace('\\', "/");
+    let path = path.replace('\\', "/");
+
+    Path::new(&path)
+        .strip_prefix(&cwd)
+        .ok()
+        .map(|relative| relative.to_string_lossy().into_owned())
+}
+
+// node_modules/@scope/pkg/dist/index.js -> @scope/pkg
+fn package_name(relative_path: &str) -> Option<String> {
+    let components: Vec<_> = Path::new(relative_path)
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => name.to_str(),
+            _ => None,
+        })
+        .collect();
+
+    // innermost package wins for nested node_modules
+    let pos = components
+        .iter()
+        .rposition(|name| *name == "node_modules")?;
+    let rest = &components[pos + 1..];
+
+    let len = match rest.first() {
+        Some(scope) if scope.starts_with('@') => 2,
+        _ => 1,
+    };
+
+    Some(rest[..len.min(rest.len())].join("/"))
+}
+
+// keeps declaration order so later patterns can refine earlier ones
+fn deserialize_routes<'de, D>(deserializer: D) -> Result<Vec<(Pattern, RouteConfig)>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct RoutesVisitor;
+
+    impl<'de> Visitor<'de> for RoutesVisitor {
+        type Value = Vec<(Pattern, RouteConfig)>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a map of route patterns to options")
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: MapAccess<'de>,
+        {
+            let mut routes = vec![];
+
+            while let Some((pattern, route_config)) = map.next_entry::<String, RouteConfig>()? {
+                routes.push((
+                    Pattern::new(&pattern).map_err(de::Error::custom)?,
+                    route_config,
+                ));
+            }
+
+            Ok(routes)
+        }
+    }
+
+    deserializer.deserialize_map(RoutesVisitor)
+}
+
+fn serialize_routes<S: Serializer>(
+    routes: &[(Pattern, RouteConfig)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(Some(routes.len()))?;
+    for (pattern, route_config) in routes {
+        map.serialize_entry(pattern.as_str(), route_config)?;
+    }
+    map.end()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Mode {
+    /// Picks the transform from the file location
+    Auto,
+    /// Always runs the page transform
+    Page,
+    /// Always runs the app transform
+    App,
+    Off,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Env {
+    /// Adds runtime checks and page names to the generated wrappers
+    Development,
+    Production,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Severity {
+    /// Fails the build
+    Error,
+    Warning,
+}
+
+/// What `Config::resolve` decides for one file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedFile {
+    pub dir_type: DirType,
+    /// Route of the file, when it is under the pages or app root
+    pub route: Option<String>,
+    /// Props kept out of serialization, after the route overrides
+    pub excluded: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DirType {
+    Page,
+    App,
+}
@@ -1,7 +1,5 @@
-use std::path::{Component, Path};
-
-use serde::Deserialize;
 use swc_core::{
+    common::DUMMY_SP,
     ecma::{ast::*, visit::*},
     plugin::{
         metadata::TransformPluginMetadataContextKind, plugin_transform,
@@ -10,81 +8,75 @@ use swc_core::{
 };
 
 use app::*;
+pub use config::*;
 use page::*;
+pub use schema::config_schema;
+use utils::{emit_error, emit_note};
 
 pub mod app;
+mod config;
 pub mod page;
+mod schema;
 mod utils;
 
-#[derive(Debug, Default, Clone, Deserialize)]
-#[serde(rename_all = "camelCase", deny_unknown_fields)]
-pub struct Config {
-    #[serde(default)]
-    pub excluded: Vec<String>,
-}
-
-pub enum DirType {
-    Page,
-    App,
-}
-
 #[plugin_transform]
-pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
-    let raw_cwd = _metadata
-        .get_context(&TransformPluginMetadataContextKind::Cwd)
-        .unwrap();
-
-    let raw_path = _metadata
-        .get_context(&TransformPluginMetadataContextKind::Filename)
-        .unwrap();
+pub fn process_transform(program: Program, metadata: TransformPluginProgramMetadata) -> Program {
+    let cwd = metadata.get_context(&TransformPluginMetadataContextKind::Cwd);
+    let path = metadata.get_context(&TransformPluginMetadataContextKind::Filename);
 
-    // Windows path separator -> Unix path separator
-    let cwd = &raw_cwd.replace('\\', "/");
-    let path = &raw_path.replace('\\', "/");
-
-    // overlapping prefix
-    let prefix = cwd
-        .chars()
-        .zip(path.chars())
-        .take_while(|(a, b)| a == b)
-        .map(|(a, _)| a)
-        .collect::<String>();
-
-    if let Some(relative_path) = path.strip_prefix(&prefix) {
-        let mut is_page = false;
-
-        for component in Path::new(relative_path).components() {
-            match component {
-                Component::Normal(str) => match str.to_str().unwrap_or_default() {
-                    // skip non-source stuff
-                    "node_modules" => {
-                        return program;
-                    }
-                    "pages" => {
-                        is_page = true;
-                        break;
-                    }
-                    _ => {}
-                },
-                _ => {}
+    let mut config = match Config::parse(
+        &metadata
+            .get_transform_plugin_config()
+            .unwrap_or_else(|| "{}".to_string()),
+    ) {
+        Ok(config) => config,
+        Err(errors) => {
+            for err in errors {
+                emit_error(
+                    DUMMY_SP,
+                    &format!(
+                        "invalid plugin config while transforming {}: {err}",
+                        path.as_deref().unwrap_or("<unknown file>")
+                    ),
+                );
             }
+            return program;
         }
+    };
 
-        // consider server components outside the app directory
-        let dir_type = if is_page { DirType::Page } else { DirType::App };
-
-        let config = serde_json::from_str::<Config>(
-            &_metadata
-                .get_transform_plugin_config()
-                .unwrap_or_else(|| "{}".to_string()),
-        )
-        .expect("Failed to parse plugin config");
+    if config.env.is_none() {
+        config.env = metadata
+            .get_context(&TransformPluginMetadataContextKind::Env)
+            .map(|env| match env.as_str() {
+                "production" => Env::Production,
+                _ => Env::Development,
+            });
+    }
 
-        match dir_type {
-            DirType::Page => program.fold_with(&mut as_folder(transform_page(config))),
-            DirType::App => program.fold_with(&mut as_folder(transform_app(config))),
+    match config.resolve_or_skip(cwd.as_deref(), path.as_deref()) {
+        Ok(file) => match file.dir_type {
+            DirType::Page => program.fold_with(&mut as_folder(transform_page(
+                config,
+                file,
+                metadata.comments,
+            ))),
+            DirType::App => program.fold_with(&mut as_folder(transform_app(
+                config,
+                file,
+                metadata.comments,
+            ))),
+        },
+        Err(reason) => {
+            if config.explain {
+                emit_note(
+                    DUMMY_SP,
+                    &format!(
+                        "{} not transformed: {reason}",
+                        path.as_deref().unwrap_or("<unknown file>")
+                    ),
+                );
+            }
+            program
         }
-    } else {
-        program
     }
 }
@@ -1,15 +1,15 @@
 use std::ops::IndexMut;
 
 use swc_core::{
-    common::{util::take::Take, DUMMY_SP},
+    common::{comments::Comments, util::take::Take, MultiSpan, Span, Spanned, DUMMY_SP},
     ecma::{
         ast::*,
-        utils::{prepend_stmt, ExprFactory},
+        utils::{find_pat_ids, prepend_stmt, ExprFactory},
         visit::*,
     },
 };
 
-use crate::{utils::*, Config};
+use crate::{utils::*, Config, Env, ResolvedFile, Severity};
 
 static SSG_EXPORTS: &[&str; 2] = &["getStaticProps", "getServerSideProps"];
 static INITIAL_PROPS: &str = "getInitialProps";
@@ -26,16 +26,34 @@ pub static SUPERJSON_INIT_PROPS_LOCAL: &str = "_withSuperJSONInitProps";
 static SUPERJSON_PAGE_IMPORTED: &str = "withSuperJSONPage";
 pub static SUPERJSON_PAGE_LOCAL: &str = "_withSuperJSONPage";
 
+// _withSuperJSONPage(Page, { page: "/blog/[slug]" })
+static PAGE_OPTION: &str = "page";
+
 // import { not_gSSP as _NEXT_SUPERJSON_IMPORTED_PROPS } from '..'
 // const  _NEXT_SUPERJSON_SSG_PROPS = wrap(_NEXT_SUPERJSON_IMPORTED_PROPS)
 // export { _NEXT_SUPERJSON_SSG_PROPS as gSSP }
 pub static NEXT_SSG_PROPS_LOCAL: &str = "_NEXT_SUPERJSON_IMPORTED_PROPS";
 pub static NEXT_SSG_PROPS_ORIG: &str = "_NEXT_SUPERJSON_SSG_PROPS";
 
+// const { getServerSideProps: _NEXT_SUPERJSON_DESTRUCTURED_PROPS } = ..
+// const getServerSideProps = wrap(_NEXT_SUPERJSON_DESTRUCTURED_PROPS)
+static NEXT_SSG_PROPS_DESTRUCTURED: &str = "_NEXT_SUPERJSON_DESTRUCTURED_PROPS";
+
 // import { unwrapped as _NEXT_SUPERJSON_IMPORTED_PAGE } from 'src'
 // export default wrap(_NEXT_SUPERJSON_IMPORTED_PAGE)
 static NEXT_PAGE_LOCAL: &str = "_NEXT_SUPERJSON_IMPORTED_PAGE";
 
+// const NextSuperJSONPageComponent = () => ..
+// const NextSuperJSONPage = wrap(NextSuperJSONPageComponent)
+// export default NextSuperJSONPage
+//
+// capitalized, as React Refresh only registers component-like bindings
+static NEXT_PAGE_COMPONENT: &str = "NextSuperJSONPageComponent";
+static NEXT_PAGE_WRAPPED: &str = "NextSuperJSONPage";
+
+// NextSuperJSONPage.displayName = "SuperJSON(Page)"
+static DISPLAY_NAME: &str = "displayName";
+
 #[derive(Default)]
 struct PositionHolder {
     orig: Option<usize>,
@@ -47,49 +65,167 @@ struct PositionHolder {
 struct TransformTarget {
     export: PositionHolder,
     ident: PositionHolder,
+    // local binding behind `export { .. as gSSP }`
+    binding: Option<Id>,
     skip: bool,
 }
 
-struct PageTransformer {
+struct PageTransformer<C: Comments> {
+    comments: C,
+    require_pragma: bool,
+
     excluded: Vec<String>,
+    env: Env,
+    route: Option<String>,
+    tools_module: String,
 
     props: TransformTarget,
     page: TransformTarget,
 
-    has_init_props: bool,
+    // bindings the default export resolves to
+    page_ids: Vec<Id>,
+    // the next class visited is the page
+    page_class: bool,
+    in_page_class: bool,
     use_init_props: bool,
+    // the first `getInitialProps` found
+    init_props_span: Span,
     keep_init_props: bool,
 
     has_multiple_props: bool,
+    conflicting_exports: Severity,
+
+    unsupported: bool,
+
+    explain: bool,
+    data_export: Option<String>,
+    page_form: Option<&'static str>,
+    skipped: Option<String>,
 }
 
-pub fn transform_page(config: Config) -> impl VisitMut {
+pub fn transform_page<C: Comments>(
+    config: Config,
+    file: ResolvedFile,
+    comments: C,
+) -> impl VisitMut {
     PageTransformer {
-        excluded: config.excluded,
+        comments,
+        require_pragma: config.require_pragma,
+
+        excluded: file.excluded,
+        env: config.env.unwrap_or(Env::Production),
+        route: file.route,
+        tools_module: config.tools_module,
 
         props: Default::default(),
         page: Default::default(),
 
-        has_init_props: false,
+        page_ids: vec![],
+        page_class: false,
+        in_page_class: false,
         use_init_props: false,
+        init_props_span: DUMMY_SP,
         keep_init_props: false,
 
         has_multiple_props: false,
+        conflicting_exports: config.conflicting_exports,
+
+        unsupported: false,
+
+        explain: config.explain,
+        data_export: None,
+        page_form: None,
+        skipped: None,
     }
 }
 
-impl VisitMut for PageTransformer {
+impl<C: Comments> VisitMut for PageTransformer<C> {
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        self.skipped = file_pragma_skip(
+            &self.comments,
+            module.body.first().map_or(module.span.lo, Spanned::span_lo),
+            module.body.iter().map(ModuleItem::as_stmt),
+            self.require_pragma,
+        )
+        .map(Into::into);
+
+        if self.skipped.is_none() {
+            module.visit_mut_children_with(self);
+        }
+
+        if self.explain {
+            emit_note(module.span.shrink_to_lo(), &self.explanation());
+        }
+    }
+
+    fn visit_mut_script(&mut self, script: &mut Script) {
+        self.skipped = file_pragma_skip(
+            &self.comments,
+            script.body.first().map_or(script.span.lo, Spanned::span_lo),
+            script.body.iter().map(Some),
+            self.require_pragma,
+        )
+        .map(Into::into);
+
+        if self.skipped.is_none() {
+            self.transform_cjs(&mut script.body);
+        }
+
+        if self.explain {
+            emit_note(script.span.shrink_to_lo(), &self.explanation());
+        }
+    }
+
     fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
         self.find_page(items);
 
         if self.page.export.orig.is_none() {
+            // module.exports = Page
+            if items.iter().all(ModuleItem::is_stmt) {
+                let mut stmts = items
+                    .take()
+                    .into_iter()
+                    .filter_map(|item| item.stmt())
+                    .collect();
+
+                self.transform_cjs(&mut stmts);
+
+                *items = stmts.into_iter().map(ModuleItem::Stmt).collect();
+                return;
+            }
+
+            self.skipped = Some("no default export".into());
             return;
         }
 
         self.find_ssg_prop(items);
 
+        if self.unsupported {
+            self.skipped = Some("unsupported data fetching declaration".into());
+            return;
+        }
+
+        if self.has_multiple_props {
+            return;
+        }
+
+        // /* @superjson-disable */
+        // export const getServerSideProps = ..
+        if [self.props.export.orig, self.props.ident.orig]
+            .into_iter()
+            .flatten()
+            .any(|pos| has_pragma(&self.comments, items[pos].span_lo(), PRAGMA_DISABLE))
+        {
+            self.skipped = Some(format!(
+                "`{}` disabled by `@superjson-disable`",
+                self.data_export.as_deref().unwrap_or_default()
+            ));
+            return;
+        }
+
         if self.props.export.orig.is_none() {
-            if !self.use_init_props || self.has_multiple_props {
+            if !self.use_init_props {
+                self.skipped = Some("no data fetching export".into());
                 return;
             }
 
@@ -98,7 +234,22 @@ impl VisitMut for PageTransformer {
 
         let mut new_items = vec![];
 
-        let mut temp_page = None;
+        // the wrapper hoists the statics of the page when called, so it goes
+        // after the declaration of Page and any `Page.getInitialProps = ..`
+        let wrap_after = self.wrap_page_after(items);
+        let mut deferred_page = vec![];
+
+        // function gSSP() .. is wrapped into a separate binding
+        let wraps_fn_decl = matches!(
+            self.props.ident.orig.map(|pos| &items[pos]),
+            Some(
+                ModuleItem::Stmt(Stmt::Decl(Decl::Fn(_)))
+                    | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                        decl: Decl::Fn(_),
+                        ..
+                    }))
+            )
+        );
 
         for (pos, item) in items.iter_mut().enumerate() {
             if self.props.ident.orig.is_some()
@@ -110,21 +261,23 @@ impl VisitMut for PageTransformer {
                     // =>
                     // gSSP = wrap(.., excluded)
                     ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => {
-                        let assign_expr = expr.as_mut_assign().unwrap();
-
-                        assign_expr.right =
-                            assign_expr.right.take().wrap_props(self.excluded_expr());
+                        if let Some(assign_expr) = expr.as_mut_assign() {
+                            assign_expr.right =
+                                assign_expr.right.take().wrap_props(self.props_args());
+                        }
 
                         new_items.push(item.take());
                     }
                     ModuleItem::Stmt(Stmt::Decl(decl)) => match decl {
                         // function gSSP ..
                         // =>
-                        // const gSSP = wrap(.., excluded)
+                        // function gSSP ..
+                        // const _NEXT_SUPERJSON_SSG_PROPS = wrap(gSSP, excluded)
                         Decl::Fn(fn_decl) => {
-                            *decl = fn_decl.take().as_wrapped_var_decl(self.excluded_expr());
+                            let id = fn_decl.ident.clone();
 
                             new_items.push(item.take());
+                            new_items.push(temp_props_item(id, self.props_args()));
                         }
                         // const gSSP = ..
                         // =>
@@ -132,23 +285,39 @@ impl VisitMut for PageTransformer {
                         Decl::Var(var_decl) => {
                             let v = var_decl.decls.index_mut(self.props.ident.decl.unwrap());
 
-                            v.init = Some(v.init.take().unwrap().wrap_props(self.excluded_expr()));
+                            // const { gSSP } = ..
+                            // =>
+                            // const { gSSP: _NEXT_SUPERJSON_DESTRUCTURED_PROPS } = ..
+                            // const gSSP = wrap(_NEXT_SUPERJSON_DESTRUCTURED_PROPS, excluded)
+                            if let Some(id) = self.destructured_binding(&v.name) {
+                                rename_binding(&mut v.name, &id);
 
-                            new_items.push(item.take());
+                                new_items.push(item.take());
+                                new_items.push(ModuleItem::Stmt(Stmt::Decl(
+                                    destructured_props_decl(id, self.props_args()),
+                                )));
+                            } else {
+                                v.init =
+                                    v.init.take().map(|init| init.wrap_props(self.props_args()));
+
+                                new_items.push(item.take());
+                            }
                         }
                         _ => {}
                     },
                     // export function not_gSSP() ..
                     // =>
-                    // export const not_gSSP = wrap(function not_gSSP()..)
+                    // export function not_gSSP() ..
+                    // const _NEXT_SUPERJSON_SSG_PROPS = wrap(not_gSSP, excluded)
                     ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
                         decl: export_decl,
                         ..
                     })) => match export_decl {
                         Decl::Fn(fn_decl) => {
-                            *export_decl = fn_decl.take().as_wrapped_var_decl(self.excluded_expr());
+                            let id = fn_decl.ident.clone();
 
                             new_items.push(item.take());
+                            new_items.push(temp_props_item(id, self.props_args()));
                         }
                         // export const not_gSSP = ..
                         // =>
@@ -156,7 +325,13 @@ impl VisitMut for PageTransformer {
                         Decl::Var(var_decl) => {
                             let v = var_decl.decls.index_mut(self.props.ident.decl.unwrap());
 
-                            v.init = Some(v.init.take().unwrap().wrap_props(self.excluded_expr()));
+                            if let Some(id) = self.destructured_binding(&v.name) {
+                                rename_binding(&mut v.name, &id);
+                                self.split_destructured_export(export_decl, id, &mut new_items);
+                            } else {
+                                v.init =
+                                    v.init.take().map(|init| init.wrap_props(self.props_args()));
+                            }
 
                             new_items.push(item.take());
                         }
@@ -175,44 +350,62 @@ impl VisitMut for PageTransformer {
                     // import { not_gSSP as _NEXT_SUPERJSON_IMPORTED_PROPS } from '..'
                     // const _NEXT_SUPERJSON_SSG_PROPS = wrap(_NEXT_SUPERJSON_IMPORTED_PROPS)
                     // export { _NEXT_SUPERJSON_SSG_PROPS as gSSP }
+                    //
+                    // import gSSP from '..' <-
+                    // export { gSSP as getServerSideProps }
+                    // =>
+                    // import gSSP from '..'
+                    // const _NEXT_SUPERJSON_SSG_PROPS = wrap(gSSP)
+                    // export { _NEXT_SUPERJSON_SSG_PROPS as getServerSideProps }
                     ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
                         specifiers, ..
                     })) => {
-                        let s = specifiers
-                            .index_mut(self.props.ident.spec.unwrap())
-                            .as_mut_named()
-                            .unwrap();
-
-                        // imported: None, local: not_gSSP
-                        // =>
-                        // imported: not_gSSP, local: _NEXT_SUPERJSON_IMPORTED_PROPS
-                        if s.imported.is_none() {
-                            s.imported = Some(ModuleExportName::Ident(s.local.take()));
-                        }
+                        let local = match specifiers.index_mut(self.props.ident.spec.unwrap()) {
+                            // imported: None, local: not_gSSP
+                            // =>
+                            // imported: not_gSSP, local: _NEXT_SUPERJSON_IMPORTED_PROPS
+                            ImportSpecifier::Named(s) => {
+                                if s.imported.is_none() {
+                                    s.imported = Some(ModuleExportName::Ident(s.local.take()));
+                                }
 
-                        s.local = Ident::new(NEXT_SSG_PROPS_LOCAL.into(), DUMMY_SP);
+                                s.local = Ident::new(NEXT_SSG_PROPS_LOCAL.into(), DUMMY_SP);
+                                s.local.clone()
+                            }
+                            ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+                            | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                                local.clone()
+                            }
+                        };
 
                         new_items.push(item.take());
-
-                        new_items.push(temp_props_item(self.excluded_expr()));
+                        new_items.push(temp_props_item(local, self.props_args()));
                     }
                     _ => {}
                 }
             } else {
+                // both exports may come from one `export { .. } from`, so the
+                // specifiers are removed once both are handled
+                let mut taken_specs = vec![];
+
                 if !self.props.skip && pos == self.props.export.orig.unwrap() {
                     match item {
                         ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
                             decl: export_decl,
                             ..
                         })) => {
+                            let mut destructured = None;
+                            let mut hoisted = None;
+
                             match export_decl {
                                 // export function gSSP..
                                 // =>
-                                // export const gSSP = wrap(.., excluded)
-                                Decl::Fn(fn_decl) => {
-                                    *export_decl =
-                                        fn_decl.take().as_wrapped_var_decl(self.excluded_expr());
-                                }
+                                // function gSSP..
+                                // const _NEXT_SUPERJSON_SSG_PROPS = wrap(gSSP, excluded)
+                                // export { _NEXT_SUPERJSON_SSG_PROPS as gSSP }
+                                //
+                                // keeps the declaration hoisted for code above it
+                                Decl::Fn(fn_decl) => hoisted = Some(fn_decl.take()),
                                 // export const gSSP = ..
                                 // =>
                                 // export const gSSP = wrap(.., excluded)
@@ -220,12 +413,33 @@ impl VisitMut for PageTransformer {
                                     let v =
                                         var_decl.decls.index_mut(self.props.export.decl.unwrap());
 
-                                    v.init = Some(
-                                        v.init.take().unwrap().wrap_props(self.excluded_expr()),
-                                    );
+                                    if let Some(id) =
+                                        ssg_binding(&v.name).filter(|_| !v.name.is_ident())
+                                    {
+                                        rename_binding(&mut v.name, &id);
+                                        destructured = Some(id);
+                                    } else {
+                                        v.init = v
+                                            .init
+                                            .take()
+                                            .map(|init| init.wrap_props(self.props_args()));
+                                    }
                                 }
                                 _ => {}
                             }
+
+                            if let Some(id) = destructured {
+                                self.split_destructured_export(export_decl, id, &mut new_items);
+                            }
+
+                            if let Some(fn_decl) = hoisted {
+                                let id = fn_decl.ident.clone();
+
+                                new_items.push(ModuleItem::Stmt(Stmt::Decl(Decl::Fn(fn_decl))));
+                                new_items.push(temp_props_item(id.clone(), self.props_args()));
+
+                                *item = temp_props_export_item(ModuleExportName::Ident(id));
+                            }
                         }
 
                         ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
@@ -233,6 +447,7 @@ impl VisitMut for PageTransformer {
                             src,
                             ..
                         })) => {
+                            // export { gSSP } from '..'
                             // export { not_gSSP as gSSP } from '..'
                             // =>
                             // import { not_gSSP as _NEXT_SUPERJSON_IMPORTED_PROPS } from '..'
@@ -251,29 +466,16 @@ impl VisitMut for PageTransformer {
                                     src,
                                 ));
 
-                                new_items.push(temp_props_item(self.excluded_expr()));
-
-                                new_items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
-                                    NamedExport {
-                                        with: None,
-                                        span: DUMMY_SP,
-                                        specifiers: vec![ExportSpecifier::Named(
-                                            ExportNamedSpecifier {
-                                                exported: s.exported.take(),
-                                                is_type_only: false,
-                                                orig: ModuleExportName::Ident(Ident::new(
-                                                    NEXT_SSG_PROPS_ORIG.into(),
-                                                    DUMMY_SP,
-                                                )),
-                                                span: DUMMY_SP,
-                                            },
-                                        )],
-                                        src: None,
-                                        type_only: false,
-                                    },
-                                )));
+                                new_items.push(temp_props_item(
+                                    Ident::new(NEXT_SSG_PROPS_LOCAL.into(), DUMMY_SP),
+                                    self.props_args(),
+                                ));
+
+                                new_items.push(temp_props_export_item(
+                                    s.exported.take().unwrap_or_else(|| s.orig.clone()),
+                                ));
 
-                                specifiers.remove(self.props.export.spec.unwrap());
+                                taken_specs.push(self.props.export.spec.unwrap());
 
                             // export { gSSP }
                             // export { not_gSSP as gSSP }
@@ -293,10 +495,14 @@ impl VisitMut for PageTransformer {
                                 // import { not_gSSP as _NEXT_SUPERJSON_IMPORTED_PROPS }
                                 // => _NEXT_SUPERJSON_SSG_PROPS
                                 //
-                                // case 2: local
+                                // case 2: local function
+                                // function gSSP() {}
+                                // => _NEXT_SUPERJSON_SSG_PROPS
+                                //
+                                // case 3: local
                                 // const gSSP = () => {}
                                 // => gSSP
-                                if self.props.ident.spec.is_some() {
+                                if self.props.ident.spec.is_some() || wraps_fn_decl {
                                     s.orig = ModuleExportName::Ident(Ident::new(
                                         NEXT_SSG_PROPS_ORIG.into(),
                                         DUMMY_SP,
@@ -308,74 +514,78 @@ impl VisitMut for PageTransformer {
                     }
                 }
 
-                let mut keep_page = false;
+                let mut wrapped_page = vec![];
+                let mut page_taken = false;
+
+                if pos == self.page.export.orig.unwrap() {
+                    let target = if wrap_after.is_some() {
+                        &mut deferred_page
+                    } else {
+                        &mut wrapped_page
+                    };
 
-                if pos == self.page.export.orig.unwrap() && !self.page.skip {
                     match item {
+                        // export default Page
+                        // export default () => ..
+                        // =>
+                        // const NextSuperJSONPageComponent = () => ..
+                        // const NextSuperJSONPage = wrap(NextSuperJSONPageComponent)
+                        // export default NextSuperJSONPage
                         ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(
                             ExportDefaultExpr { expr, .. },
                         )) => {
-                            keep_page = true;
-                            *expr = expr.take().wrap_page();
+                            let (page, anonymous) =
+                                name_anonymous_component(expr.take(), &mut new_items);
+
+                            self.push_wrapped_page(page, anonymous, target);
+                            target.push(wrapped_page_export());
+                            page_taken = true;
+                        }
+                        // export default class Page ..
+                        // =>
+                        // class Page ..
+                        // const NextSuperJSONPage = wrap(Page)
+                        // export default NextSuperJSONPage
+                        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(
+                            ExportDefaultDecl {
+                                decl: DefaultDecl::Class(ClassExpr { ident, class }),
+                                ..
+                            },
+                        )) => {
+                            let anonymous = ident.is_none();
+                            let id = ident.take().unwrap_or_else(page_component_ident);
+
+                            new_items.push(ModuleItem::Stmt(Stmt::Decl(Decl::Class(ClassDecl {
+                                ident: id.clone(),
+                                declare: false,
+                                class: class.take(),
+                            }))));
+                            self.push_wrapped_page(Box::new(Expr::Ident(id)), anonymous, target);
+                            target.push(wrapped_page_export());
+                            page_taken = true;
                         }
+                        // export default function Page() ..
+                        // =>
+                        // function Page() ..
+                        // const NextSuperJSONPage = wrap(Page)
+                        // export default NextSuperJSONPage
                         ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(
-                            ExportDefaultDecl { decl, .. },
+                            ExportDefaultDecl {
+                                decl: DefaultDecl::Fn(FnExpr { ident, function }),
+                                ..
+                            },
                         )) => {
-                            keep_page = true;
-                            // TODO: remove duplicate code
-                            match decl {
-                                DefaultDecl::Class(class_expr) => {
-                                    if class_expr.ident.is_some() {
-                                        let id = class_expr.ident.as_ref().unwrap().clone();
-
-                                        new_items.push(ModuleItem::Stmt(Stmt::Decl(
-                                            class_expr.take().as_class_decl().unwrap().into(),
-                                        )));
-
-                                        *item = ModuleItem::ModuleDecl(
-                                            ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
-                                                expr: Box::new(Expr::Ident(id)).wrap_page(),
-                                                span: DUMMY_SP,
-                                            }),
-                                        );
-                                    } else {
-                                        let expr: Box<Expr> = Box::new(class_expr.take().into());
-
-                                        *item = ModuleItem::ModuleDecl(
-                                            ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
-                                                expr: expr.wrap_page(),
-                                                span: DUMMY_SP,
-                                            }),
-                                        );
-                                    }
-                                }
-                                DefaultDecl::Fn(fn_expr) => {
-                                    if fn_expr.ident.is_some() {
-                                        let id = fn_expr.ident.as_ref().unwrap().clone();
-
-                                        new_items.push(ModuleItem::Stmt(Stmt::Decl(
-                                            fn_expr.take().as_fn_decl().unwrap().into(),
-                                        )));
-
-                                        *item = ModuleItem::ModuleDecl(
-                                            ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
-                                                expr: Box::new(Expr::Ident(id)).wrap_page(),
-                                                span: DUMMY_SP,
-                                            }),
-                                        );
-                                    } else {
-                                        let expr: Box<Expr> = Box::new(fn_expr.take().into());
-
-                                        *item = ModuleItem::ModuleDecl(
-                                            ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
-                                                expr: expr.wrap_page(),
-                                                span: DUMMY_SP,
-                                            }),
-                                        );
-                                    }
-                                }
-                                _ => {}
-                            }
+                            let anonymous = ident.is_none();
+                            let id = ident.take().unwrap_or_else(page_component_ident);
+
+                            new_items.push(ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl {
+                                ident: id.clone(),
+                                declare: false,
+                                function: function.take(),
+                            }))));
+                            self.push_wrapped_page(Box::new(Expr::Ident(id)), anonymous, target);
+                            target.push(wrapped_page_export());
+                            page_taken = true;
                         }
                         ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
                             specifiers,
@@ -389,9 +599,11 @@ impl VisitMut for PageTransformer {
                                 .unwrap();
 
                             // export { unwrapped as default } from 'src'
+                            // export { default } from 'src'
                             // =>
                             // import { unwrapped as _NEXT_SUPERJSON_IMPORTED_PAGE } from 'src'
-                            // export default wrap(_NEXT_SUPERJSON_IMPORTED_PAGE)
+                            // const NextSuperJSONPage = wrap(_NEXT_SUPERJSON_IMPORTED_PAGE)
+                            // export default NextSuperJSONPage
                             if let Some(src) = src {
                                 new_items.push(temp_import_item(
                                     s.orig.clone(),
@@ -399,34 +611,30 @@ impl VisitMut for PageTransformer {
                                     src,
                                 ));
 
-                                let new_page = ModuleItem::ModuleDecl(
-                                    ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
-                                        expr: Box::new(Expr::Ident(Ident::new(
-                                            NEXT_PAGE_LOCAL.into(),
-                                            DUMMY_SP,
-                                        )))
-                                        .wrap_page(),
-                                        span: DUMMY_SP,
-                                    }),
+                                self.push_wrapped_page(
+                                    Box::new(Expr::Ident(Ident::new(
+                                        NEXT_PAGE_LOCAL.into(),
+                                        DUMMY_SP,
+                                    ))),
+                                    false,
+                                    target,
                                 );
-                                temp_page = Some(new_page);
+                                target.push(wrapped_page_export());
 
                             // export { Page as default }
                             // =>
-                            // export default wrap(Page, excluded)
-                            } else {
-                                if let ModuleExportName::Ident(id) = &s.orig {
-                                    let new_page = ModuleItem::ModuleDecl(
-                                        ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
-                                            expr: Box::new(Expr::Ident(id.clone())).wrap_page(),
-                                            span: DUMMY_SP,
-                                        }),
-                                    );
-                                    temp_page = Some(new_page);
-                                }
+                            // const NextSuperJSONPage = wrap(Page)
+                            // export default NextSuperJSONPage
+                            } else if let ModuleExportName::Ident(id) = &s.orig {
+                                self.push_wrapped_page(
+                                    Box::new(Expr::Ident(id.clone())),
+                                    false,
+                                    target,
+                                );
+                                target.push(wrapped_page_export());
                             }
 
-                            specifiers.remove(self.page.export.spec.unwrap());
+                            taken_specs.push(self.page.export.spec.unwrap());
                         }
                         _ => {}
                     }
@@ -437,71 +645,71 @@ impl VisitMut for PageTransformer {
                         specifiers,
                         ..
                     })) => {
-                        if !specifiers.is_empty() {
-                            if !keep_page {
-                                new_items.push(item.take());
-                            } else {
-                                temp_page = Some(item.take());
-                            }
+                        taken_specs.sort_unstable();
+                        for spec in taken_specs.into_iter().rev() {
+                            specifiers.remove(spec);
                         }
-                    }
-                    _ => {
-                        if !keep_page {
+
+                        if !specifiers.is_empty() {
                             new_items.push(item.take());
-                        } else {
-                            temp_page = Some(item.take());
                         }
                     }
+                    // replaced by the wrapped page
+                    _ if page_taken => {}
+                    _ => new_items.push(item.take()),
                 }
+
+                new_items.append(&mut wrapped_page);
             }
-        }
 
-        if let Some(tmp) = temp_page {
-            new_items.push(tmp);
+            if Some(pos) == wrap_after {
+                new_items.append(&mut deferred_page);
+            }
         }
 
         // TODO: these two stmts can be combined
         if !self.props.skip {
             prepend_stmt(
                 &mut new_items,
-                superjson_import_decl(SUPERJSON_PROPS_IMPORTED),
+                superjson_import_decl(SUPERJSON_PROPS_IMPORTED, &self.tools_module),
             );
         }
         if self.use_init_props {
             prepend_stmt(
                 &mut new_items,
-                superjson_import_decl(SUPERJSON_INIT_PROPS_IMPORTED),
-            );
-        }
-        if !self.page.skip {
-            prepend_stmt(
-                &mut new_items,
-                superjson_import_decl(SUPERJSON_PAGE_IMPORTED),
+                superjson_import_decl(SUPERJSON_INIT_PROPS_IMPORTED, &self.tools_module),
             );
         }
+        prepend_stmt(
+            &mut new_items,
+            superjson_import_decl(SUPERJSON_PAGE_IMPORTED, &self.tools_module),
+        );
 
         *items = new_items;
     }
 
     fn visit_mut_class_member(&mut self, member: &mut ClassMember) {
         member.visit_mut_children_with(self);
+
+        // /* @superjson-disable */
+        // static getInitialProps = ..
+        if !self.in_page_class || has_pragma(&self.comments, member.span_lo(), PRAGMA_DISABLE) {
+            return;
+        }
+
         match member {
-            ClassMember::ClassProp(p) => {
+            ClassMember::ClassProp(p) if p.is_static => {
                 if let PropName::Ident(id) = &p.key {
                     if &*id.sym == INITIAL_PROPS {
-                        if let Some(expr) = &mut p.value {
-                            self.use_init_props = true;
-                            if !self.keep_init_props {
-                                p.value = Some(expr.take().wrap_init_props(self.excluded_expr()));
-                            }
-                        }
+                        let span = p.span;
+                        p.value = p.value.take().map(|expr| self.init_props(span, expr));
                     }
                 }
             }
-            ClassMember::Method(m) => {
+            ClassMember::Method(m) if m.is_static => {
                 if let PropName::Ident(id) = &m.key {
                     if &*id.sym == INITIAL_PROPS {
-                        self.use_init_props = true;
+                        self.found_init_props(m.span);
                         if !self.keep_init_props {
                             *member = ClassMember::ClassProp(ClassProp {
                                 accessibility: m.accessibility.take(),
@@ -521,7 +729,7 @@ impl VisitMut for PageTransformer {
                                         function: m.function.take(),
                                         ident: None,
                                     }))
-                                    .wrap_init_props(self.excluded_expr()),
+                                    .wrap_init_props(self.props_args()),
                                 ),
                             });
                         }
@@ -535,57 +743,601 @@ impl VisitMut for PageTransformer {
     fn visit_mut_assign_expr(&mut self, a: &mut AssignExpr) {
         a.visit_mut_children_with(self);
 
-        if a.left.is_simple() {
-            if let Some(mut expr) = a.left.take().simple() {
-                if let Some(MemberExpr { prop, .. }) = expr.as_mut_member() {
-                    prop.visit_mut_children_with(self);
-                }
+        // Page.getInitialProps = ..
+        // Page["getInitialProps"] = ..
+        let is_page_init_props = a.op == op!("=")
+            && matches!(
+                a.left.as_simple(),
+                Some(SimpleAssignTarget::Member(MemberExpr { obj, prop, .. }))
+                    if is_init_props_member(prop) && self.is_page(obj)
+            );
+
+        // /* @superjson-disable */
+        // Page.getInitialProps = ..
+        if !is_page_init_props || has_pragma(&self.comments, a.span.lo, PRAGMA_DISABLE) {
+            return;
+        }
+
+        a.right = self.init_props(a.span, a.right.take());
+    }
+
+    // Object.assign(Page, { getInitialProps })
+    fn visit_mut_call_expr(&mut self, call: &mut CallExpr) {
+        call.visit_mut_children_with(self);
+
+        let is_page_assign = matches!(
+            call.callee.as_expr().and_then(|callee| callee.as_member()),
+            Some(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) if obj.is_ident_ref_to("Object") && &*prop.sym == "assign"
+        ) && call
+            .args
+            .first()
+            .map_or(false, |arg| self.is_page(&arg.expr));
+
+        if !is_page_assign || has_pragma(&self.comments, call.span.lo, PRAGMA_DISABLE) {
+            return;
+        }
+
+        let span = call.span;
+
+        for arg in call.args.iter_mut().skip(1) {
+            let Expr::Object(ObjectLit { props, .. }) = &mut *arg.expr else {
+                continue;
+            };
+
+            for prop in props.iter_mut() {
+                let PropOrSpread::Prop(prop) = prop else {
+                    continue;
+                };
+
+                match &mut **prop {
+                    // { getInitialProps }
+                    Prop::Shorthand(id) if &*id.sym == INITIAL_PROPS => {
+                        if self.keep_init_props {
+                            self.found_init_props(span);
+                            continue;
+                        }
 
-                if self.has_init_props {
-                    if !self.keep_init_props {
-                        a.right = a.right.take().wrap_init_props(self.excluded_expr());
+                        **prop = Prop::KeyValue(KeyValueProp {
+                            key: PropName::Ident(Ident::new(INITIAL_PROPS.into(), DUMMY_SP)),
+                            value: self.init_props(span, Box::new(Expr::Ident(id.take()))),
+                        });
                     }
-                    self.use_init_props = true;
-                    self.has_init_props = false;
-                }
+                    // { getInitialProps: .. }
+                    Prop::KeyValue(KeyValueProp { key, value }) if is_init_props_key(key) => {
+                        *value = self.init_props(span, value.take());
+                    }
+                    // { getInitialProps() {} }
+                    Prop::Method(MethodProp { key, function }) if is_init_props_key(key) => {
+                        if self.keep_init_props {
+                            self.found_init_props(span);
+                            continue;
+                        }
 
-                a.left = AssignTarget::Simple(expr);
+                        **prop = Prop::KeyValue(KeyValueProp {
+                            key: key.take(),
+                            value: self.init_props(
+                                span,
+                                Box::new(Expr::Fn(FnExpr {
+                                    ident: None,
+                                    function: function.take(),
+                                })),
+                            ),
+                        });
+                    }
+                    _ => {}
+                }
             }
         }
     }
 
-    fn visit_mut_member_prop(&mut self, p: &mut MemberProp) {
-        p.visit_mut_children_with(self);
+    fn visit_mut_class(&mut self, class: &mut Class) {
+        let in_page_class = self.in_page_class;
 
-        if let Some(id) = p.as_ident() {
-            if &*id.sym == INITIAL_PROPS {
-                self.has_init_props = true;
-            }
-        }
+        self.in_page_class = self.page_class;
+        self.page_class = false;
+
+        class.visit_mut_children_with(self);
+
+        self.in_page_class = in_page_class;
+    }
+
+    // class Page ..
+    fn visit_mut_class_decl(&mut self, decl: &mut ClassDecl) {
+        self.page_class = self.page_ids.contains(&decl.ident.to_id());
+        decl.visit_mut_children_with(self);
+    }
+
+    // const Page = class ..
+    fn visit_mut_var_declarator(&mut self, decl: &mut VarDeclarator) {
+        self.page_class = matches!(
+            (&decl.name, decl.init.as_deref()),
+            (Pat::Ident(id), Some(Expr::Class(_))) if self.page_ids.contains(&id.to_id())
+        );
+        decl.visit_mut_children_with(self);
+    }
+
+    // export default class ..
+    fn visit_mut_export_default_decl(&mut self, decl: &mut ExportDefaultDecl) {
+        self.page_class = decl.decl.is_class();
+        decl.visit_mut_children_with(self);
     }
 }
 
-impl PageTransformer {
-    pub fn excluded_expr(&mut self) -> ExprOrSpread {
-        ExprOrSpread {
-            spread: None,
-            expr: Box::new(Expr::Array(ArrayLit {
+impl<C: Comments> PageTransformer<C> {
+    fn is_page(&self, expr: &Expr) -> bool {
+        expr.as_ident()
+            .map_or(false, |id| self.page_ids.contains(&id.to_id()))
+    }
+
+    fn found_init_props(&mut self, span: Span) {
+        if !self.use_init_props {
+            self.init_props_span = span;
+        }
+        self.use_init_props = true;
+    }
+
+    // Next.js rejects these pages itself, so they are reported and left alone
+    fn conflicting_exports(&mut self, first: (&str, Span), second: (&str, Span)) {
+        self.has_multiple_props = true;
+        self.skipped = Some(format!("both `{}` and `{}` present", first.0, second.0));
+
+        emit(
+            self.conflicting_exports,
+            MultiSpan::from_spans(vec![first.1, second.1]),
+            &format!(
+                "`{}` and `{}` can't be used in the same page, which is left untransformed",
+                first.0, second.0
+            ),
+        );
+    }
+
+    // wrap(gIP, excluded) unless gSSP/gSP make it unsupported
+    fn init_props(&mut self, span: Span, expr: Box<Expr>) -> Box<Expr> {
+        self.found_init_props(span);
+
+        if self.keep_init_props {
+            expr
+        } else {
+            expr.wrap_init_props(self.props_args())
+        }
+    }
+
+    // pages router, data fetching: `getServerSideProps`, default export: `export default function`, transformed
+    fn explanation(&self) -> String {
+        let data = match (&self.data_export, self.use_init_props) {
+            (Some(name), _) => format!("`{name}`"),
+            (None, true) => format!("`{INITIAL_PROPS}`"),
+            (None, false) => "none".into(),
+        };
+
+        let outcome = match &self.skipped {
+            Some(reason) => format!("not transformed: {reason}"),
+            None => "transformed".into(),
+        };
+
+        format!(
+            "pages router, data fetching: {data}, default export: {}, {outcome}",
+            self.page_form.unwrap_or("none")
+        )
+    }
+
+    // wrap(gSSP, excluded, options)
+    //
+    // production builds leave out what the runtime defaults to
+    pub fn props_args(&self) -> Vec<ExprOrSpread> {
+        match self.env {
+            Env::Development => vec![excluded_arg(&self.excluded), self.options_arg()],
+            Env::Production if self.excluded.is_empty() => vec![],
+            Env::Production => vec![excluded_arg(&self.excluded)],
+        }
+    }
+
+    // wrap(Page, options)
+    pub fn page_args(&self) -> Vec<ExprOrSpread> {
+        match self.env {
+            Env::Development => vec![self.options_arg()],
+            Env::Production => vec![],
+        }
+    }
+
+    // { page: "/blog/[slug]" } turns on the runtime checks
+    fn options_arg(&self) -> ExprOrSpread {
+        Expr::Object(ObjectLit {
+            span: DUMMY_SP,
+            props: self
+                .route
+                .iter()
+                .map(|route| {
+                    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                        key: PropName::Ident(Ident::new(PAGE_OPTION.into(), DUMMY_SP)),
+                        value: route.as_str().into(),
+                    })))
+                })
+                .collect(),
+        })
+        .as_arg()
+    }
+
+    // SuperJSON(BlogSlugPage) for an anonymous pages/blog/[slug]
+    fn display_name(&self) -> String {
+        let name = self
+            .route
+            .as_deref()
+            .map_or_else(|| "Page".into(), route_component_name);
+
+        format!("SuperJSON({name})")
+    }
+
+    // export const { gSSP, other } = ..
+    // =>
+    // const { gSSP: _NEXT_SUPERJSON_DESTRUCTURED_PROPS, other } = ..
+    // export { other }
+    // export const gSSP = wrap(_NEXT_SUPERJSON_DESTRUCTURED_PROPS, excluded)
+    fn split_destructured_export(
+        &self,
+        export_decl: &mut Decl,
+        id: Ident,
+        new_items: &mut Vec<ModuleItem>,
+    ) {
+        let decl = std::mem::replace(export_decl, destructured_props_decl(id, self.props_args()));
+
+        let others: Vec<Ident> = find_pat_ids(&decl);
+
+        new_items.push(ModuleItem::Stmt(Stmt::Decl(decl)));
+
+        let specifiers: Vec<ExportSpecifier> = others
+            .into_iter()
+            .filter(|id| &*id.sym != NEXT_SSG_PROPS_DESTRUCTURED)
+            .map(|id| {
+                ExportSpecifier::Named(ExportNamedSpecifier {
+                    span: DUMMY_SP,
+                    orig: ModuleExportName::Ident(id),
+                    exported: None,
+                    is_type_only: false,
+                })
+            })
+            .collect();
+
+        if !specifiers.is_empty() {
+            new_items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(
+                NamedExport {
+                    span: DUMMY_SP,
+                    specifiers,
+                    src: None,
+                    type_only: false,
+                    with: None,
+                },
+            )));
+        }
+    }
+
+    // const NextSuperJSONPage = wrap(page)
+    // NextSuperJSONPage.displayName = "SuperJSON(Page)"
+    //
+    // the runtime names the wrapper after the page, so the display name is
+    // only set for a component that was anonymous in the source
+    fn push_wrapped_page<T: From<Stmt>>(
+        &self,
+        page: Box<Expr>,
+        anonymous: bool,
+        items: &mut Vec<T>,
+    ) {
+        let wrapped = Ident::new(NEXT_PAGE_WRAPPED.into(), DUMMY_SP);
+
+        items.push(
+            Stmt::Decl(Decl::Var(Box::new(VarDecl {
                 span: DUMMY_SP,
-                elems: self
-                    .excluded
-                    .iter()
-                    .map(|e| {
-                        Some(ExprOrSpread {
-                            spread: None,
-                            expr: Box::new(Expr::Lit(Lit::Str(Str {
-                                span: DUMMY_SP,
-                                value: e.clone().into(),
-                                raw: None,
-                            }))),
+                kind: VarDeclKind::Const,
+                declare: false,
+                decls: vec![VarDeclarator {
+                    span: DUMMY_SP,
+                    name: Pat::Ident(wrapped.clone().into()),
+                    init: Some(page.wrap_page(self.page_args())),
+                    definite: false,
+                }],
+            })))
+            .into(),
+        );
+
+        if !anonymous {
+            return;
+        }
+
+        items.push(
+            Stmt::Expr(ExprStmt {
+                span: DUMMY_SP,
+                expr: Box::new(Expr::Assign(AssignExpr {
+                    span: DUMMY_SP,
+                    op: op!("="),
+                    left: wrapped
+                        .make_member(Ident::new(DISPLAY_NAME.into(), DUMMY_SP))
+                        .into(),
+                    right: self.display_name().into(),
+                })),
+            })
+            .into(),
+        );
+    }
+
+    // where `export { Page as default }` is followed by a class or variable declaring Page
+    fn lexical_page_decl(&self, items: &[ModuleItem]) -> Option<usize> {
+        let export = self.page.export.orig?;
+
+        let Some(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+            specifiers,
+            src: None,
+            ..
+        }))) = items.get(export)
+        else {
+            return None;
+        };
+
+        let Some(ExportSpecifier::Named(ExportNamedSpecifier {
+            orig: ModuleExportName::Ident(orig),
+            ..
+        })) = specifiers.get(self.page.export.spec?)
+        else {
+            return None;
+        };
+
+        let page = orig.to_id();
+
+        items
+            .iter()
+            .enumerate()
+            .skip(export + 1)
+            .find_map(|(pos, item)| {
+                let decl = match item {
+                    ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+                    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => decl,
+                    _ => return None,
+                };
+
+                let declared = match decl {
+                    Decl::Class(ClassDecl { ident, .. }) => ident.to_id() == page,
+                    Decl::Var(var_decl) => find_pat_ids::<_, Id>(&var_decl.decls).contains(&page),
+                    _ => false,
+                };
+
+                declared.then_some(pos)
+            })
+    }
+
+    // position of the last item the wrapper has to follow, if any: the
+    // declaration of Page or a later top-level `Page.x = ..` or
+    // `Object.assign(Page, ..)`
+    fn wrap_page_after(&self, items: &[ModuleItem]) -> Option<usize> {
+        let export = self.page.export.orig?;
+
+        let last_static = items
+            .iter()
+            .enumerate()
+            .skip(export + 1)
+            .filter(|(_, item)| {
+                item.as_stmt()
+                    .map_or(false, |stmt| self.is_page_static(stmt))
+            })
+            .map(|(pos, _)| pos)
+            .last();
+
+        self.lexical_page_decl(items).max(last_static)
+    }
+
+    fn is_page_static(&self, stmt: &Stmt) -> bool {
+        let Stmt::Expr(ExprStmt { expr, .. }) = stmt else {
+            return false;
+        };
+
+        match &**expr {
+            // Page.getInitialProps = ..
+            Expr::Assign(AssignExpr {
+                op: op!("="),
+                left: AssignTarget::Simple(SimpleAssignTarget::Member(member)),
+                ..
+            }) => self.is_page(&member.obj),
+            // Object.assign(Page, ..)
+            Expr::Call(CallExpr {
+                callee: Callee::Expr(callee),
+                args,
+                ..
+            }) => {
+                let is_assign = callee.as_member().map_or(false, |member| {
+                    member
+                        .obj
+                        .as_ident()
+                        .map_or(false, |id| &*id.sym == "Object")
+                        && member
+                            .prop
+                            .as_ident()
+                            .map_or(false, |id| &*id.sym == "assign")
+                });
+
+                is_assign && args.first().map_or(false, |arg| self.is_page(&arg.expr))
+            }
+            _ => false,
+        }
+    }
+
+    // const { gSSP } = ..
+    fn destructured_binding(&self, pat: &Pat) -> Option<Ident> {
+        if pat.is_ident() {
+            return None;
+        }
+
+        find_pat_ids::<_, Ident>(pat)
+            .into_iter()
+            .find(|id| Some(id.to_id()) == self.props.binding)
+    }
+
+    // const Wrapped = withLayout(Page)
+    // export default Wrapped
+    fn find_hoc_args(&mut self, stmts: &[&Stmt]) {
+        let mut n = 0;
+        while n < self.page_ids.len() {
+            let hoc_args = stmts.iter().find_map(|stmt| match stmt {
+                Stmt::Decl(Decl::Var(var_decl)) => {
+                    var_decl
+                        .decls
+                        .iter()
+                        .find_map(|decl| match (&decl.name, &decl.init) {
+                            (Pat::Ident(id), Some(init)) if id.to_id() == self.page_ids[n] => {
+                                Some(page_ids(init))
+                            }
+                            _ => None,
                         })
-                    })
-                    .collect(),
-            })),
+                }
+                _ => None,
+            });
+
+            for id in hoc_args.into_iter().flatten() {
+                if !self.page_ids.contains(&id) {
+                    self.page_ids.push(id);
+                }
+            }
+            n += 1;
+        }
+    }
+
+    // module.exports = Page
+    // exports.getServerSideProps = ..
+    fn transform_cjs(&mut self, stmts: &mut Vec<Stmt>) {
+        let es_module = stmts.iter().any(is_es_module_marker);
+
+        let Some(page) = stmts.iter().position(|stmt| {
+            matches!(cjs_export(stmt), Some(("default", value)) if !is_export_placeholder(value))
+        }) else {
+            self.skipped = Some("no default export or `module.exports`".into());
+            return;
+        };
+
+        self.page_form = Some("`module.exports =`");
+        self.page_ids = cjs_export(&stmts[page]).map_or(vec![], |(_, value)| page_ids(value));
+        self.find_hoc_args(&stmts.iter().collect::<Vec<_>>());
+
+        let props: Vec<(usize, &str)> = stmts
+            .iter()
+            .enumerate()
+            .filter_map(|(pos, stmt)| match cjs_export(stmt) {
+                Some((name, value))
+                    if SSG_EXPORTS.contains(&name) && !is_export_placeholder(value) =>
+                {
+                    Some((pos, name))
+                }
+                _ => None,
+            })
+            .collect();
+
+        if let [(first_pos, first), (second_pos, second), ..] = props.as_slice() {
+            self.conflicting_exports(
+                (first, stmts[*first_pos].span()),
+                (second, stmts[*second_pos].span()),
+            );
+            return;
+        }
+
+        let props = props.first().map(|&(pos, name)| (pos, name.to_string()));
+        self.data_export = props.as_ref().map(|(_, name)| name.clone());
+
+        // Next.js's own compiled `_app` compares `App.origGetInitialProps` with
+        // `App.getInitialProps`, so compiled ES modules need a data fetching export
+        if es_module && props.is_none() {
+            self.skipped = Some("compiled ES module (`__esModule`) without `getServerSideProps` or `getStaticProps`".into());
+            return;
+        }
+
+        // check initial props
+        self.keep_init_props = props.is_some();
+        stmts.visit_mut_children_with(self);
+
+        if let Some((pos, name)) = props.as_ref().filter(|_| self.use_init_props) {
+            self.conflicting_exports(
+                (name, stmts[*pos].span()),
+                (INITIAL_PROPS, self.init_props_span),
+            );
+            return;
+        }
+
+        match props {
+            // /* @superjson-disable */
+            // exports.getServerSideProps = ..
+            Some((pos, name))
+                if has_pragma(&self.comments, stmts[pos].span_lo(), PRAGMA_DISABLE) =>
+            {
+                self.skipped = Some(format!("`{name}` disabled by `@superjson-disable`"));
+                return;
+            }
+            Some((pos, _)) => {
+                if let Some(value) = cjs_export_value(&mut stmts[pos]) {
+                    *value = value.take().wrap_props(self.props_args());
+                }
+            }
+            None if !self.use_init_props => {
+                self.skipped = Some("no data fetching export".into());
+                return;
+            }
+            None => self.props.skip = true,
+        }
+
+        // module.exports = Page
+        // =>
+        // const NextSuperJSONPage = wrap(Page)
+        // module.exports = NextSuperJSONPage
+        //
+        // after any later `Page.getInitialProps = ..`, which the wrapper hoists
+        if let Some(value) = cjs_export_value(&mut stmts[page]) {
+            let page_expr = std::mem::replace(
+                value,
+                Box::new(Expr::Ident(Ident::new(NEXT_PAGE_WRAPPED.into(), DUMMY_SP))),
+            );
+
+            let mut decls = vec![];
+            let (page_expr, anonymous) = name_anonymous_component(page_expr, &mut decls);
+            self.push_wrapped_page(page_expr, anonymous, &mut decls);
+
+            let last_static = (page + 1..stmts.len())
+                .filter(|&pos| self.is_page_static(&stmts[pos]))
+                .last();
+
+            if let Some(last_static) = last_static {
+                let export = stmts.remove(page);
+                decls.push(export);
+                stmts.splice(last_static..last_static, decls);
+            } else {
+                stmts.splice(page..page, decls);
+            }
+        }
+
+        if !self.props.skip {
+            prepend_stmt(
+                stmts,
+                superjson_require_decl(SUPERJSON_PROPS_IMPORTED, &self.tools_module),
+            );
+        }
+        if self.use_init_props {
+            prepend_stmt(
+                stmts,
+                superjson_require_decl(SUPERJSON_INIT_PROPS_IMPORTED, &self.tools_module),
+            );
+        }
+        prepend_stmt(
+            stmts,
+            superjson_require_decl(SUPERJSON_PAGE_IMPORTED, &self.tools_module),
+        );
+    }
+
+    // export let getServerSideProps;
+    fn check_initialized(&mut self, decl: &VarDeclarator) {
+        if decl.init.is_none() {
+            emit_warning(
+                decl.span,
+                "data fetching function declared without an initializer is not supported, page \
+                 left untouched",
+            );
+            self.unsupported = true;
         }
     }
 
@@ -593,25 +1345,42 @@ impl PageTransformer {
         let mut ssg_prop_ident = None;
 
         let mut first = None;
+        let mut first_span = DUMMY_SP;
 
         items.iter_mut().enumerate().any(|(pos, item)| {
-            let found = match item {
+            // every data fetching export of the item, with its own span
+            let mut found = vec![];
+
+            match item {
                 // check has ssg props
                 ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
                     match decl {
-                        Decl::Fn(fn_decl) => SSG_EXPORTS.contains(&&*fn_decl.ident.sym),
+                        Decl::Fn(fn_decl) => {
+                            if SSG_EXPORTS.contains(&&*fn_decl.ident.sym) {
+                                found.push((fn_decl.ident.sym.to_string(), fn_decl.ident.span));
+                            }
+                        }
                         Decl::Var(var_decl) => {
-                            let pos = var_decl.decls.iter().position(|decl| {
-                                SSG_EXPORTS.contains(&&*decl.name.as_ident().unwrap().sym)
-                            });
+                            // export const { getServerSideProps } = .. too
+                            for (decl_pos, decl) in var_decl.decls.iter().enumerate() {
+                                let names = ssg_bindings(&decl.name);
+                                if names.is_empty() {
+                                    continue;
+                                }
 
-                            if self.props.export.decl.is_none() {
-                                self.props.export.decl = pos;
-                            }
+                                if found.is_empty() {
+                                    self.check_initialized(decl);
 
-                            pos.is_some()
+                                    if self.props.export.decl.is_none() {
+                                        self.props.export.decl = Some(decl_pos);
+                                    }
+                                }
+                                found.extend(
+                                    names.into_iter().map(|id| (id.sym.to_string(), id.span)),
+                                );
+                            }
                         }
-                        _ => false,
+                        _ => {}
                     }
                 }
                 ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
@@ -619,52 +1388,70 @@ impl PageTransformer {
                     src,
                     ..
                 })) => {
-                    let pos = specifiers.iter().position(|specifier| match specifier {
-                        ExportSpecifier::Named(ExportNamedSpecifier {
+                    for (spec_pos, specifier) in specifiers.iter().enumerate() {
+                        let ExportSpecifier::Named(ExportNamedSpecifier {
                             orig: ModuleExportName::Ident(orig_id),
                             exported,
+                            span,
                             ..
-                        }) => {
-                            let exported_as = match exported {
-                                Some(ModuleExportName::Ident(exported_id)) => &exported_id.sym,
-                                _ => &orig_id.sym,
-                            };
-
-                            if SSG_EXPORTS.contains(&&**exported_as) {
-                                self.props.skip = src.is_some()
-                                    && (exported.is_none() || (&&**exported_as == &&*orig_id.sym));
-
-                                if !self.props.skip {
-                                    ssg_prop_ident = Some((*orig_id.sym).to_string());
-                                }
-                                return true;
-                            }
-                            false
+                        }) = specifier
+                        else {
+                            continue;
+                        };
+
+                        let exported_as = match exported {
+                            Some(ModuleExportName::Ident(exported_id)) => &exported_id.sym,
+                            _ => &orig_id.sym,
+                        };
+
+                        if !SSG_EXPORTS.contains(&&**exported_as) {
+                            continue;
                         }
-                        _ => false,
-                    });
 
-                    if self.props.export.spec.is_none() {
-                        self.props.export.spec = pos;
-                    }
+                        if found.is_empty() {
+                            // `export { gSSP } from '..'` has no local binding
+                            if src.is_none() {
+                                ssg_prop_ident = Some(orig_id.to_id());
+                            }
 
-                    pos.is_some()
+                            if self.props.export.spec.is_none() {
+                                self.props.export.spec = Some(spec_pos);
+                            }
+                        }
+                        found.push((exported_as.to_string(), *span));
+                    }
                 }
-                _ => false,
-            };
+                _ => {}
+            }
+
+            // export const getServerSideProps = .., getStaticProps = ..
+            // export { a as getServerSideProps, b as getStaticProps }
+            if let [first, second, ..] = found.as_slice() {
+                self.conflicting_exports((&first.0, first.1), (&second.0, second.1));
+                return true;
+            }
+
+            let found_name = found.pop().map(|(name, _)| name);
+            let found = found_name.is_some();
 
             if found {
                 if first.is_some() {
-                    self.has_multiple_props = true;
+                    let first_name = self.data_export.clone().unwrap_or_default();
+                    self.conflicting_exports(
+                        (&first_name, first_span),
+                        (&found_name.unwrap_or_default(), item.span()),
+                    );
                     return true;
                 }
                 first = Some(pos);
+                first_span = item.span();
+                self.data_export = found_name;
             }
 
             false
         });
 
-        if self.has_multiple_props {
+        if self.has_multiple_props || self.unsupported {
             return;
         }
 
@@ -676,149 +1463,136 @@ impl PageTransformer {
             .for_each(|item| item.visit_mut_children_with(self));
 
         if first.is_some() && self.use_init_props {
-            self.has_multiple_props = true;
+            let first_name = self.data_export.clone().unwrap_or_default();
+            self.conflicting_exports(
+                (&first_name, first_span),
+                (INITIAL_PROPS, self.init_props_span),
+            );
             return;
         }
 
         self.props.export.orig = first;
 
-        if ssg_prop_ident.is_some() && !self.props.skip {
-            let mut n = items.len();
+        // the exported binding, looked up among top level declarations only
+        let Some(target) = ssg_prop_ident.filter(|_| !self.props.skip) else {
+            return;
+        };
+        self.props.binding = Some(target.clone());
 
-            while n > 0 {
-                n -= 1;
+        let mut n = items.len();
 
-                if self.props.export.orig.unwrap() == n {
-                    continue;
-                }
-
-                match &items[n] {
-                    // gSSP = ..
-                    ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => {
-                        if expr.is_assign() {
-                            let assign = expr.as_assign().unwrap();
+        while n > 0 {
+            n -= 1;
 
-                            let left = assign.left.as_ident();
+            if self.props.export.orig.unwrap() == n {
+                continue;
+            }
 
-                            if left.is_some() {
-                                if assign.op == op!("=")
-                                    && &*left.unwrap().sym == ssg_prop_ident.as_ref().unwrap()
-                                {
-                                    self.props.ident.orig = Some(n);
-                                    break;
-                                }
-                            }
-                        }
-                    }
-                    // function gSSP() ..
-                    // const gSSP = ..
-                    ModuleItem::Stmt(Stmt::Decl(decl)) => match decl {
-                        Decl::Fn(fn_decl) => {
-                            if &*fn_decl.ident.sym == ssg_prop_ident.as_ref().unwrap() {
+            match &items[n] {
+                // gSSP = ..
+                ModuleItem::Stmt(Stmt::Expr(ExprStmt { expr, .. })) => {
+                    if let Some(assign) = expr.as_assign() {
+                        if let Some(left) = assign.left.as_ident() {
+                            if assign.op == op!("=") && left.to_id() == target {
                                 self.props.ident.orig = Some(n);
                                 break;
                             }
                         }
-                        Decl::Var(var_decl) => {
-                            self.props.ident.decl = var_decl.decls.iter().position(|decl| {
-                                let id = decl.name.as_ident();
-
-                                if id.is_some()
-                                    && &*id.unwrap().sym == ssg_prop_ident.as_ref().unwrap()
-                                {
-                                    self.props.ident.orig = Some(n);
-                                    return true;
-                                }
-
-                                false
-                            });
-
-                            if self.props.ident.decl.is_some() {
-                                break;
-                            }
+                    }
+                }
+                // function gSSP() ..
+                // const gSSP = ..
+                ModuleItem::Stmt(Stmt::Decl(decl)) => match decl {
+                    Decl::Fn(fn_decl) => {
+                        if fn_decl.ident.to_id() == target {
+                            self.props.ident.orig = Some(n);
+                            break;
                         }
-                        _ => {}
-                    },
-
-                    // export function not_gSSP() ..
-                    // export const not_gSSP = ..
-                    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
-                        decl: export_decl,
-                        ..
-                    })) => match export_decl {
-                        Decl::Fn(fn_decl) => {
-                            if &*fn_decl.ident.sym == ssg_prop_ident.as_ref().unwrap() {
+                    }
+                    Decl::Var(var_decl) => {
+                        // const { gSSP } = .. too
+                        self.props.ident.decl = var_decl.decls.iter().position(|decl| {
+                            if find_pat_ids::<_, Id>(&decl.name).contains(&target) {
                                 self.props.ident.orig = Some(n);
-                                break;
+                                return true;
                             }
-                        }
-                        Decl::Var(var_decl) => {
-                            self.props.ident.decl = var_decl.decls.iter().position(|decl| {
-                                let id = decl.name.as_ident();
-
-                                if id.is_some()
-                                    && &*id.unwrap().sym == ssg_prop_ident.as_ref().unwrap()
-                                {
-                                    self.props.ident.orig = Some(n);
-                                    return true;
-                                }
 
-                                false
-                            });
+                            false
+                        });
 
-                            if self.props.ident.decl.is_some() {
-                                break;
-                            }
+                        if let Some(pos) = self.props.ident.decl {
+                            self.check_initialized(&var_decl.decls[pos]);
+                            break;
                         }
-                        _ => {}
-                    },
-                    // import { gSSP } from '..'
-                    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
-                        specifiers, ..
-                    })) => {
-                        self.props.ident.spec = specifiers.iter().position(|specifier| {
-                            if let ImportSpecifier::Named(ImportNamedSpecifier {
-                                local,
-                                imported,
-                                ..
-                            }) = specifier
-                            {
-                                if &*local.sym == ssg_prop_ident.as_ref().unwrap() {
-                                    if imported.is_some() {
-                                        if let ModuleExportName::Ident(ident) =
-                                            imported.as_ref().unwrap()
-                                        {
-                                            self.props.skip = SSG_EXPORTS.contains(&&*ident.sym);
-                                        }
-                                    }
+                    }
+                    _ => {}
+                },
 
-                                    self.props.ident.orig = Some(n);
-                                    return true;
-                                }
+                // export function not_gSSP() ..
+                // export const not_gSSP = ..
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: export_decl,
+                    ..
+                })) => match export_decl {
+                    Decl::Fn(fn_decl) => {
+                        if fn_decl.ident.to_id() == target {
+                            self.props.ident.orig = Some(n);
+                            break;
+                        }
+                    }
+                    Decl::Var(var_decl) => {
+                        // const { gSSP } = .. too
+                        self.props.ident.decl = var_decl.decls.iter().position(|decl| {
+                            if find_pat_ids::<_, Id>(&decl.name).contains(&target) {
+                                self.props.ident.orig = Some(n);
+                                return true;
                             }
+
                             false
                         });
 
-                        if self.props.ident.orig.is_some() {
+                        if let Some(pos) = self.props.ident.decl {
+                            self.check_initialized(&var_decl.decls[pos]);
                             break;
                         }
                     }
                     _ => {}
+                },
+                // import { gSSP } from '..'
+                // import gSSP from '..'
+                // import * as gSSP from '..'
+                ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl { specifiers, .. })) => {
+                    self.props.ident.spec = specifiers.iter().position(|specifier| {
+                        let local = match specifier {
+                            ImportSpecifier::Named(ImportNamedSpecifier { local, .. })
+                            | ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })
+                            | ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                                local
+                            }
+                        };
+
+                        if local.to_id() == target {
+                            self.props.ident.orig = Some(n);
+                            return true;
+                        }
+                        false
+                    });
+
+                    if self.props.ident.orig.is_some() {
+                        break;
+                    }
                 }
+                _ => {}
             }
         }
     }
 
-    pub fn find_page(&mut self, items: &Vec<ModuleItem>) {
+    pub fn find_page(&mut self, items: &[ModuleItem]) {
         self.page.export.orig = items.iter().position(|item| match item {
             // check has page
             ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(_)) => true,
             ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(_)) => true,
-            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
-                specifiers,
-                src,
-                ..
-            })) => {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport { specifiers, .. })) => {
                 self.page.export.spec = specifiers.iter().position(|spec| match spec {
                     ExportSpecifier::Named(ExportNamedSpecifier {
                         orig: ModuleExportName::Ident(Ident { sym, .. }),
@@ -827,16 +1601,9 @@ impl PageTransformer {
                     }) => match exported {
                         Some(ModuleExportName::Ident(Ident {
                             sym: exported_sym, ..
-                        })) => {
-                            self.page.skip =
-                                exported_sym == "default" && sym == "default" && src.is_some();
-                            exported_sym == "default"
-                        }
-                        _ => {
-                            // export { default } from 'source' -> skip
-                            self.page.skip = src.is_some() && sym == "default";
-                            self.page.skip
-                        }
+                        })) => exported_sym == "default",
+                        // export { default } from 'source'
+                        _ => sym == "default",
                     },
                     _ => false,
                 });
@@ -844,6 +1611,315 @@ impl PageTransformer {
                 self.page.export.spec.is_some()
             }
             _ => false,
-        })
+        });
+
+        self.page_ids =
+            match self.page.export.orig.map(|pos| &items[pos]) {
+                // export default Page
+                // export default withLayout(Page)
+                Some(ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(
+                    ExportDefaultExpr { expr, .. },
+                ))) => page_ids(expr),
+                // export default function Page() ..
+                Some(ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(
+                    ExportDefaultDecl { decl, .. },
+                ))) => match decl {
+                    DefaultDecl::Class(ClassExpr {
+                        ident: Some(id), ..
+                    })
+                    | DefaultDecl::Fn(FnExpr {
+                        ident: Some(id), ..
+                    }) => vec![id.to_id()],
+                    _ => vec![],
+                },
+                // export { Page as default }
+                Some(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                    specifiers,
+                    src: None,
+                    ..
+                }))) => match &specifiers[self.page.export.spec.unwrap()] {
+                    ExportSpecifier::Named(ExportNamedSpecifier {
+                        orig: ModuleExportName::Ident(id),
+                        ..
+                    }) => vec![id.to_id()],
+                    _ => vec![],
+                },
+                _ => vec![],
+            };
+
+        self.find_hoc_args(
+            &items
+                .iter()
+                .filter_map(ModuleItem::as_stmt)
+                .collect::<Vec<_>>(),
+        );
+
+        self.page_form = self.page.export.orig.map(|pos| match &items[pos] {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl: DefaultDecl::Class(_),
+                ..
+            })) => "`export default class`",
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                decl: DefaultDecl::Fn(_),
+                ..
+            })) => "`export default function`",
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                src: Some(_), ..
+            })) => "`export { .. as default } from`",
+            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(_)) => "`export { .. as default }`",
+            _ => "`export default` expression",
+        });
+    }
+}
+
+// the component and whatever HOC calls wrap it
+//
+// only the first argument of a HOC is the wrapped component, the others
+// are options or helpers like `withLayout(Page, Layout)`
+fn page_ids(expr: &Expr) -> Vec<Id> {
+    match expr {
+        Expr::Ident(id) => vec![id.to_id()],
+        Expr::Paren(ParenExpr { expr, .. }) => page_ids(expr),
+        Expr::Call(CallExpr { args, .. }) => args.first().map_or(vec![], |arg| page_ids(&arg.expr)),
+        _ => vec![],
+    }
+}
+
+fn is_init_props_key(key: &PropName) -> bool {
+    match key {
+        PropName::Ident(id) => &*id.sym == INITIAL_PROPS,
+        PropName::Str(s) => &*s.value == INITIAL_PROPS,
+        _ => false,
+    }
+}
+
+fn is_init_props_member(prop: &MemberProp) -> bool {
+    match prop {
+        MemberProp::Ident(id) => &*id.sym == INITIAL_PROPS,
+        MemberProp::Computed(ComputedPropName { expr, .. }) => {
+            matches!(&**expr, Expr::Lit(Lit::Str(s)) if &*s.value == INITIAL_PROPS)
+        }
+        _ => false,
+    }
+}
+
+// /blog/[slug] => BlogSlugPage
+fn route_component_name(route: &str) -> String {
+    let name: String = route
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word[..1].to_ascii_uppercase() + &word[1..])
+        .collect();
+
+    match name.as_str() {
+        "" => "IndexPage".into(),
+        _ => format!("{name}Page"),
+    }
+}
+
+fn page_component_ident() -> Ident {
+    Ident::new(NEXT_PAGE_COMPONENT.into(), DUMMY_SP)
+}
+
+// () => ..
+// =>
+// const NextSuperJSONPageComponent = () => ..
+//
+// also returns whether the component was named here
+fn name_anonymous_component<T: From<Stmt>>(
+    expr: Box<Expr>,
+    items: &mut Vec<T>,
+) -> (Box<Expr>, bool) {
+    match &*expr {
+        Expr::Arrow(_)
+        | Expr::Fn(FnExpr { ident: None, .. })
+        | Expr::Class(ClassExpr { ident: None, .. }) => {
+            items.push(named_component_decl(expr).into());
+            (Box::new(Expr::Ident(page_component_ident())), true)
+        }
+        _ => (expr, false),
+    }
+}
+
+fn named_component_decl(expr: Box<Expr>) -> Stmt {
+    Stmt::Decl(Decl::Var(Box::new(VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Const,
+        declare: false,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(page_component_ident().into()),
+            init: Some(expr),
+            definite: false,
+        }],
+    })))
+}
+
+// export default NextSuperJSONPage
+fn wrapped_page_export() -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+        span: DUMMY_SP,
+        expr: Box::new(Expr::Ident(Ident::new(NEXT_PAGE_WRAPPED.into(), DUMMY_SP))),
+    }))
+}
+
+// export const { getServerSideProps } = ..
+fn ssg_binding(pat: &Pat) -> Option<Ident> {
+    find_pat_ids::<_, Ident>(pat)
+        .into_iter()
+        .find(|id| SSG_EXPORTS.contains(&&*id.sym))
+}
+
+fn ssg_bindings(pat: &Pat) -> Vec<Ident> {
+    find_pat_ids::<_, Ident>(pat)
+        .into_iter()
+        .filter(|id| SSG_EXPORTS.contains(&&*id.sym))
+        .collect()
+}
+
+// const getServerSideProps = wrap(_NEXT_SUPERJSON_DESTRUCTURED_PROPS, excluded)
+fn destructured_props_decl(id: Ident, args: Vec<ExprOrSpread>) -> Decl {
+    Decl::Var(Box::new(VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Const,
+        declare: false,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(id.into()),
+            init: Some(
+                Box::new(Expr::Ident(Ident::new(
+                    NEXT_SSG_PROPS_DESTRUCTURED.into(),
+                    DUMMY_SP,
+                )))
+                .wrap_props(args),
+            ),
+            definite: false,
+        }],
+    }))
+}
+
+// { getServerSideProps } => { getServerSideProps: _NEXT_SUPERJSON_DESTRUCTURED_PROPS }
+fn rename_binding(pat: &mut Pat, id: &Ident) {
+    pat.visit_mut_with(&mut BindingRenamer {
+        from: id.to_id(),
+        to: Ident::new(NEXT_SSG_PROPS_DESTRUCTURED.into(), DUMMY_SP),
+    });
+}
+
+struct BindingRenamer {
+    from: Id,
+    to: Ident,
+}
+
+impl VisitMut for BindingRenamer {
+    // defaults and computed keys are not bindings
+    fn visit_mut_expr(&mut self, _: &mut Expr) {}
+
+    fn visit_mut_object_pat_prop(&mut self, prop: &mut ObjectPatProp) {
+        match prop {
+            ObjectPatProp::Assign(AssignPatProp { key, value, .. }) if key.to_id() == self.from => {
+                let left = Pat::Ident(self.to.clone().into());
+
+                *prop = ObjectPatProp::KeyValue(KeyValuePatProp {
+                    key: PropName::Ident(Ident::new(key.sym.clone(), key.span)),
+                    value: Box::new(match value.take() {
+                        Some(right) => Pat::Assign(AssignPat {
+                            span: DUMMY_SP,
+                            left: Box::new(left),
+                            right,
+                        }),
+                        None => left,
+                    }),
+                });
+            }
+            _ => prop.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_binding_ident(&mut self, id: &mut BindingIdent) {
+        if id.to_id() == self.from {
+            id.id = self.to.clone();
+        }
+    }
+}
+
+// module.exports = ..          -> default
+// exports.default = ..         -> default
+// module.exports.gSSP = ..     -> gSSP
+fn cjs_export(stmt: &Stmt) -> Option<(&str, &Expr)> {
+    let Stmt::Expr(ExprStmt { expr, .. }) = stmt else {
+        return None;
+    };
+    let AssignExpr {
+        op: op!("="),
+        left: AssignTarget::Simple(SimpleAssignTarget::Member(member)),
+        right,
+        ..
+    } = expr.as_assign()?
+    else {
+        return None;
+    };
+
+    let name = match (&*member.obj, &member.prop) {
+        (Expr::Ident(obj), MemberProp::Ident(prop))
+            if &*obj.sym == "module" && &*prop.sym == "exports" =>
+        {
+            "default"
+        }
+        (obj, MemberProp::Ident(prop)) if is_exports_object(obj) => &*prop.sym,
+        _ => return None,
+    };
+
+    Some((name, &**right))
+}
+
+fn cjs_export_value(stmt: &mut Stmt) -> Option<&mut Box<Expr>> {
+    match stmt {
+        Stmt::Expr(ExprStmt { expr, .. }) => expr.as_mut_assign().map(|assign| &mut assign.right),
+        _ => None,
+    }
+}
+
+// exports
+// module.exports
+fn is_exports_object(expr: &Expr) -> bool {
+    match expr {
+        Expr::Ident(id) => &*id.sym == "exports",
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) => obj.is_ident_ref_to("module") && &*prop.sym == "exports",
+        _ => false,
+    }
+}
+
+// exports.default = void 0
+// exports.default = exports.getServerSideProps = void 0
+fn is_export_placeholder(value: &Expr) -> bool {
+    match value {
+        Expr::Unary(UnaryExpr {
+            op: op!("void"), ..
+        }) => true,
+        Expr::Assign(AssignExpr { right, .. }) => is_export_placeholder(right),
+        _ => false,
+    }
+}
+
+// Object.defineProperty(exports, "__esModule", { value: true })
+// exports.__esModule = true
+fn is_es_module_marker(stmt: &Stmt) -> bool {
+    let Stmt::Expr(ExprStmt { expr, .. }) = stmt else {
+        return false;
+    };
+
+    match &**expr {
+        Expr::Call(CallExpr { args, .. }) => matches!(
+            args.as_slice(),
+            [target, ExprOrSpread { expr: key, .. }, ..]
+                if is_exports_object(&target.expr)
+                    && matches!(&**key, Expr::Lit(Lit::Str(s)) if &*s.value == "__esModule")
+        ),
+        _ => cjs_export(stmt).map_or(false, |(name, _)| name == "__esModule"),
     }
 }
@@ -0,0 +1,211 @@
+use serde_json::{json, Map, Value};
+
+use crate::Config;
+
+/// JSON Schema of the plugin options, for editors and `next.config.js` type checks
+pub fn config_schema() -> Value {
+    let mut schema = json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "next-superjson-plugin options",
+        "type": "object",
+        "additionalProperties": false,
+        "properties": {
+            "mode": {
+                "description": "Transform to run: picked from the file location, forced, or none",
+                "enum": ["auto", "page", "app", "off"]
+            },
+            "env": {
+                "description": "Code generation target, taken from the host when unset",
+                "enum": ["development", "production", null]
+            },
+            "explain": boolean("Report why each file was or wasn't transformed"),
+            "requirePragma": boolean(
+                "Only transform files with `\"use superjson\"` or `/* @superjson */`"
+            ),
+            "conflictingExports": {
+                "description": "How a page with conflicting data fetching exports is reported",
+                "enum": ["error", "warning"]
+            },
+            "excluded": string_list("Shorthand for `pages.excluded`"),
+            "pages": {
+                "description": "Options of the Pages Router transform",
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "enabled": boolean("Whether pages are transformed"),
+                    "excluded": string_list(
+                        "Props passed through data fetching functions without serialization"
+                        )
+                }
+            },
+            "app": {
+                "description": "Options of the App Router transform",
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "enabled": boolean("Whether server components are transformed"),
+                    "directive": {
+                        "description": "JSX attribute marking elements whose props are serialized",
+                        "type": "string"
+                    },
+                    "excluded": string_list(
+                        "Props passed to client components without serialization"
+                        )
+                }
+            },
+            "pagesDirs": string_list(
+                "Roots of the Pages Router, relative to the project directory"
+                ),
+            "pageExtensions": string_list(
+                "Next.js `pageExtensions`, telling pages apart from colocated files"
+                ),
+            "appDirs": string_list(
+                "Roots of the App Router, relative to the project directory"
+                ),
+            "fallback": {
+                "description": "Transform used for files outside both roots, left alone when unset",
+                "enum": ["page", "app", null]
+            },
+            "transpilePackages": string_list(
+                "Packages under node_modules that are transformed"
+                ),
+            "include": string_list("Globs of files to transform, all files when empty"),
+            "ignore": string_list(
+                "Globs of files to leave alone, taking precedence over `include`"
+                ),
+            "routes": {
+                "description": "Overrides keyed by route globs, later patterns winning",
+                "type": "object",
+                "additionalProperties": {
+                    "type": "object",
+                    "additionalProperties": false,
+                    "properties": {
+                        "enabled": {
+                            "description": "Whether files of the route are transformed",
+                            "type": "boolean"
+                        },
+                        "excluded": {
+                            "description": "Props kept out of serialization for the route",
+                            "type": "array",
+                            "items": { "type": "string" }
+                        }
+                    }
+                }
+            },
+            "toolsModule": {
+                "description": "Module the generated server-side helpers are imported from",
+                "type": "string"
+            },
+            "clientModule": {
+                "description": "Module the generated `SuperJSONComponent` is imported from",
+                "type": "string"
+            }
+        }
+    });
+
+    let defaults = serde_json::to_value(Config::default()).expect("options serialize to JSON");
+    with_defaults(&mut schema, &defaults);
+
+    schema
+}
+
+// the defaults documented for each option are the ones `Config::default()` uses
+fn with_defaults(schema: &mut Value, defaults: &Value) {
+    let Some(properties) = schema.get_mut("properties").and_then(Value::as_object_mut) else {
+        return;
+    };
+
+    for (key, property) in properties {
+        if let Some(default) = defaults.get(key) {
+            with_defaults(property, default);
+            property["default"] = default.clone();
+        }
+    }
+}
+
+fn string_list(description: &str) -> Value {
+    json!({
+        "description": description,
+        "type": "array",
+        "items": { "type": "string" }
+    })
+}
+
+fn boolean(description: &str) -> Value {
+    json!({
+        "description": description,
+        "type": "boolean"
+    })
+}
+
+/// Describes every key of `options` the schema doesn't know, suggesting the closest one
+pub(crate) fn unknown_options(options: &Value) -> Vec<String> {
+    let mut errors = vec![];
+    check_keys(options, &config_schema(), "", &mut errors);
+    errors
+}
+
+fn check_keys(value: &Value, schema: &Value, path: &str, errors: &mut Vec<String>) {
+    let Some(object) = value.as_object() else {
+        return;
+    };
+
+    let properties = schema.get("properties").and_then(Value::as_object);
+
+    for (key, value) in object {
+        let key_path = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{path}.{key}")
+        };
+
+        match properties.and_then(|properties| properties.get(key)) {
+            Some(schema) => check_keys(value, schema, &key_path, errors),
+            None => match schema.get("additionalProperties") {
+                // routes: { "/admin/**": { .. } }
+                Some(schema) if schema.is_object() => check_keys(value, schema, &key_path, errors),
+                _ => errors.push(unknown_option(&key_path, key, properties)),
+            },
+        }
+    }
+}
+
+fn unknown_option(key_path: &str, key: &str, properties: Option<&Map<String, Value>>) -> String {
+    let normalize = |key: &str| key.replace(['_', '-'], "").to_lowercase();
+
+    let suggestion = properties.and_then(|properties| {
+        properties
+            .keys()
+            .map(|known| (distance(&normalize(key), &normalize(known)), known))
+            .filter(|(distance, _)| *distance <= 2.max(key.len() / 3))
+            .min_by_key(|(distance, _)| *distance)
+    });
+
+    match suggestion {
+        Some((_, known)) => format!("unknown option `{key_path}`, did you mean `{known}`?"),
+        None => format!("unknown option `{key_path}`"),
+    }
+}
+
+// Levenshtein distance
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+
+        for (j, b) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = if a == *b {
+                prev
+            } else {
+                1 + prev.min(row[j]).min(row[j + 1])
+            };
+            prev = cur;
+        }
+    }
+
+    row[b.len()]
+}
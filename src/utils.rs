@@ -1,14 +1,17 @@
 use swc_core::{
-    common::{util::take::Take, DUMMY_SP},
+    common::{comments::Comments, errors::HANDLER, BytePos, MultiSpan, Span, DUMMY_SP},
     ecma::{ast::*, utils::ExprFactory},
 };
 
-use crate::page::{
-    NEXT_SSG_PROPS_LOCAL, NEXT_SSG_PROPS_ORIG, SUPERJSON_INIT_PROPS_LOCAL, SUPERJSON_PAGE_LOCAL,
-    SUPERJSON_PROPS_LOCAL,
+use crate::{
+    page::{
+        NEXT_SSG_PROPS_ORIG, SUPERJSON_INIT_PROPS_LOCAL, SUPERJSON_PAGE_LOCAL,
+        SUPERJSON_PROPS_LOCAL,
+    },
+    Severity,
 };
 
-pub fn superjson_import_decl(superjson_import_name: &str) -> ModuleItem {
+pub fn superjson_import_decl(superjson_import_name: &str, src: &str) -> ModuleItem {
     ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
         with: None,
         phase: ImportPhase::Evaluation,
@@ -31,24 +34,122 @@ pub fn superjson_import_decl(superjson_import_name: &str) -> ModuleItem {
         })],
         src: Box::new(Str {
             span: DUMMY_SP,
-            value: "next-superjson-plugin/tools".into(),
+            value: src.into(),
             raw: None,
         }),
     }))
 }
 
-pub fn temp_props_item(excluded: ExprOrSpread) -> ModuleItem {
+// /* @superjson-disable */
+pub static PRAGMA_DISABLE: &str = "@superjson-disable";
+// /* @superjson */
+static PRAGMA_ENABLE: &str = "@superjson";
+// "use superjson"
+static DIRECTIVE_ENABLE: &str = "use superjson";
+
+// checks the comments right before `pos`, `/** @pragma */` included
+pub fn has_pragma<C: Comments>(comments: &C, pos: BytePos, pragma: &str) -> bool {
+    comments.get_leading(pos).map_or(false, |comments| {
+        comments
+            .iter()
+            .any(|comment| comment.text.trim().trim_start_matches('*').trim() == pragma)
+    })
+}
+
+// reason to leave the whole file alone, if any
+//
+// `pos` is where the first statement starts, `stmts` the top level statements.
+// A `@superjson-disable` before the first statement always applies to the
+// whole file, even when that statement is a data fetching export
+pub fn file_pragma_skip<'a, C: Comments>(
+    comments: &C,
+    pos: BytePos,
+    mut stmts: impl Iterator<Item = Option<&'a Stmt>>,
+    require_pragma: bool,
+) -> Option<&'static str> {
+    if has_pragma(comments, pos, PRAGMA_DISABLE) {
+        return Some("disabled by `@superjson-disable`");
+    }
+
+    if !require_pragma || has_pragma(comments, pos, PRAGMA_ENABLE) {
+        return None;
+    }
+
+    // directive prologue
+    let has_directive = stmts
+        .by_ref()
+        .map_while(|stmt| match stmt {
+            Some(Stmt::Expr(ExprStmt { expr, .. })) => match &**expr {
+                Expr::Lit(Lit::Str(Str { value, .. })) => Some(value),
+                _ => None,
+            },
+            _ => None,
+        })
+        .any(|value| &**value == DIRECTIVE_ENABLE);
+
+    (!has_directive)
+        .then_some("`requirePragma` is on and the file has no `\"use superjson\"` or `@superjson`")
+}
+
+// const { withSuperJSONProps: _withSuperJSONProps } = require("next-superjson-plugin/tools");
+pub fn superjson_require_decl(superjson_import_name: &str, src: &str) -> Stmt {
+    Stmt::Decl(Decl::Var(Box::new(VarDecl {
+        span: DUMMY_SP,
+        kind: VarDeclKind::Const,
+        declare: false,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Object(ObjectPat {
+                span: DUMMY_SP,
+                props: vec![ObjectPatProp::KeyValue(KeyValuePatProp {
+                    key: PropName::Ident(Ident::new(superjson_import_name.into(), DUMMY_SP)),
+                    value: Box::new(Pat::Ident(
+                        Ident::new(format!("_{superjson_import_name}").into(), DUMMY_SP).into(),
+                    )),
+                })],
+                optional: false,
+                type_ann: None,
+            }),
+            init: Some(Box::new(Expr::Call(CallExpr {
+                span: DUMMY_SP,
+                callee: Ident::new("require".into(), DUMMY_SP).as_callee(),
+                args: vec![Expr::Lit(Lit::Str(src.into())).as_arg()],
+                type_args: None,
+            }))),
+            definite: false,
+        }],
+    })))
+}
+
+pub fn excluded_arg(excluded: &[String]) -> ExprOrSpread {
+    ExprOrSpread {
+        spread: None,
+        expr: Box::new(Expr::Array(ArrayLit {
+            span: DUMMY_SP,
+            elems: excluded
+                .iter()
+                .map(|e| {
+                    Some(ExprOrSpread {
+                        spread: None,
+                        expr: Box::new(Expr::Lit(Lit::Str(Str {
+                            span: DUMMY_SP,
+                            value: e.clone().into(),
+                            raw: None,
+                        }))),
+                    })
+                })
+                .collect(),
+        })),
+    }
+}
+
+// const _NEXT_SUPERJSON_SSG_PROPS = wrap(local, ...args)
+pub fn temp_props_item(local: Ident, args: Vec<ExprOrSpread>) -> ModuleItem {
     ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
         declare: false,
         decls: vec![VarDeclarator {
             definite: false,
-            init: Some(
-                Box::new(Expr::Ident(Ident::new(
-                    NEXT_SSG_PROPS_LOCAL.into(),
-                    DUMMY_SP,
-                )))
-                .wrap_props(excluded),
-            ),
+            init: Some(Box::new(Expr::Ident(local)).wrap_props(args)),
             name: Pat::Ident(BindingIdent {
                 id: Ident::new(NEXT_SSG_PROPS_ORIG.into(), DUMMY_SP),
                 type_ann: None,
@@ -60,6 +161,22 @@ pub fn temp_props_item(excluded: ExprOrSpread) -> ModuleItem {
     }))))
 }
 
+// export { _NEXT_SUPERJSON_SSG_PROPS as exported }
+pub fn temp_props_export_item(exported: ModuleExportName) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+        with: None,
+        span: DUMMY_SP,
+        specifiers: vec![ExportSpecifier::Named(ExportNamedSpecifier {
+            exported: Some(exported),
+            is_type_only: false,
+            orig: ModuleExportName::Ident(Ident::new(NEXT_SSG_PROPS_ORIG.into(), DUMMY_SP)),
+            span: DUMMY_SP,
+        })],
+        src: None,
+        type_only: false,
+    }))
+}
+
 pub fn temp_import_item(imported: ModuleExportName, local: &str, src: &mut Str) -> ModuleItem {
     ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
         with: None,
@@ -77,32 +194,33 @@ pub fn temp_import_item(imported: ModuleExportName, local: &str, src: &mut Str)
     }))
 }
 
+// wrap(self, ...args)
 pub trait Wrapper {
-    fn wrap_props(self, excluded: ExprOrSpread) -> Box<Expr>;
-    fn wrap_init_props(self, excluded: ExprOrSpread) -> Box<Expr>;
-    fn wrap_page(self) -> Box<Expr>;
+    fn wrap_props(self, args: Vec<ExprOrSpread>) -> Box<Expr>;
+    fn wrap_init_props(self, args: Vec<ExprOrSpread>) -> Box<Expr>;
+    fn wrap_page(self, args: Vec<ExprOrSpread>) -> Box<Expr>;
 }
 
 impl Wrapper for Box<Expr> {
-    fn wrap_props(self, excluded: ExprOrSpread) -> Box<Expr> {
+    fn wrap_props(self, args: Vec<ExprOrSpread>) -> Box<Expr> {
         Box::new(Expr::Call(CallExpr {
-            args: vec![self.as_arg(), excluded],
+            args: [vec![self.as_arg()], args].concat(),
             callee: Ident::new(SUPERJSON_PROPS_LOCAL.into(), DUMMY_SP).as_callee(),
             span: DUMMY_SP,
             type_args: None,
         }))
     }
-    fn wrap_init_props(self, excluded: ExprOrSpread) -> Box<Expr> {
+    fn wrap_init_props(self, args: Vec<ExprOrSpread>) -> Box<Expr> {
         Box::new(Expr::Call(CallExpr {
-            args: vec![self.as_arg(), excluded],
+            args: [vec![self.as_arg()], args].concat(),
             callee: Ident::new(SUPERJSON_INIT_PROPS_LOCAL.into(), DUMMY_SP).as_callee(),
             span: DUMMY_SP,
             type_args: None,
         }))
     }
-    fn wrap_page(self) -> Box<Expr> {
+    fn wrap_page(self, args: Vec<ExprOrSpread>) -> Box<Expr> {
         Box::new(Expr::Call(CallExpr {
-            args: vec![self.as_arg()],
+            args: [vec![self.as_arg()], args].concat(),
             callee: Ident::new(SUPERJSON_PAGE_LOCAL.into(), DUMMY_SP).as_callee(),
             span: DUMMY_SP,
             type_args: None,
@@ -110,31 +228,31 @@ impl Wrapper for Box<Expr> {
     }
 }
 
-pub trait DeclUtil {
-    fn as_wrapped_var_decl(self, excluded: ExprOrSpread) -> Decl;
+pub fn emit_error(span: impl Into<MultiSpan>, msg: &str) {
+    HANDLER.with(|handler| {
+        handler
+            .struct_span_err(span, &format!("next-superjson-plugin: {msg}"))
+            .emit()
+    });
 }
 
-impl DeclUtil for FnDecl {
-    fn as_wrapped_var_decl(mut self, excluded: ExprOrSpread) -> Decl {
-        Decl::Var(Box::new(VarDecl {
-            declare: false,
-            decls: vec![VarDeclarator {
-                definite: false,
-                init: Some(
-                    Box::new(Expr::Fn(FnExpr {
-                        function: self.function.take(),
-                        ident: None,
-                    }))
-                    .wrap_props(excluded),
-                ),
-                name: Pat::Ident(BindingIdent {
-                    id: self.ident.take(),
-                    type_ann: None,
-                }),
-                span: DUMMY_SP,
-            }],
-            kind: VarDeclKind::Const,
-            span: DUMMY_SP,
-        }))
+pub fn emit_warning(span: impl Into<MultiSpan>, msg: &str) {
+    HANDLER.with(|handler| {
+        handler
+            .struct_span_warn(span, &format!("next-superjson-plugin: {msg}"))
+            .emit()
+    });
+}
+
+pub fn emit(severity: Severity, span: impl Into<MultiSpan>, msg: &str) {
+    match severity {
+        Severity::Error => emit_error(span, msg),
+        Severity::Warning => emit_warning(span, msg),
     }
 }
+
+pub fn emit_note(span: Span, msg: &str) {
+    HANDLER.with(|handler| {
+        handler.span_note_without_error(span, &format!("next-superjson-plugin: {msg}"))
+    });
+}
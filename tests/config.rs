@@ -0,0 +1,360 @@
+use next_superjson::{config_schema, Config, DirType, Mode, ResolvedFile};
+
+fn dir_type(file: Option<ResolvedFile>) -> Option<DirType> {
+    file.map(|file| file.dir_type)
+}
+
+#[test]
+fn dir_type_default_roots() {
+    let config = Config::default();
+
+    assert_eq!(config.dir_type("pages/index.tsx"), Some(DirType::Page));
+    assert_eq!(
+        config.dir_type("/src/pages/blog/[id].tsx"),
+        Some(DirType::Page)
+    );
+    assert_eq!(config.dir_type("app/layout.tsx"), Some(DirType::App));
+    assert_eq!(
+        config.dir_type("src/app/(marketing)/pages/foo.tsx"),
+        Some(DirType::App)
+    );
+    assert_eq!(config.dir_type("components/pages/Header.tsx"), None);
+    assert_eq!(config.dir_type("lib/date.ts"), None);
+}
+
+#[test]
+fn dir_type_custom_roots() {
+    let config = Config {
+        pages_dirs: vec!["web/pages/".into()],
+        app_dirs: vec!["web".into()],
+        fallback: Some(DirType::App),
+        ..Default::default()
+    };
+
+    assert_eq!(config.dir_type("web/pages/index.tsx"), Some(DirType::Page));
+    assert_eq!(config.dir_type("web/layout.tsx"), Some(DirType::App));
+    assert_eq!(config.dir_type("pages/index.tsx"), Some(DirType::App));
+}
+
+#[test]
+fn include_and_ignore() {
+    let config: Config = serde_json::from_str(
+        r#"{
+            "include": ["pages/**", "src/app/**/*.tsx"],
+            "ignore": ["**/*.test.tsx", "**/*.stories.*"]
+        }"#,
+    )
+    .unwrap();
+
+    assert!(config.is_included("pages/index.tsx"));
+    assert!(config.is_included("/pages/blog/[id].tsx"));
+    assert!(config.is_included("src/app/layout.tsx"));
+    assert!(!config.is_included("src/app/layout.ts"));
+    assert!(!config.is_included("pages/index.test.tsx"));
+    assert!(!config.is_included("src/app/Button.stories.tsx"));
+    assert!(!config.is_included("lib/date.ts"));
+
+    assert!(Config::default().is_included("lib/date.ts"));
+    assert!(serde_json::from_str::<Config>(r#"{ "ignore": ["[a"] }"#).is_err());
+}
+
+#[test]
+fn route_from_path() {
+    let config = Config::default();
+
+    assert_eq!(config.route("pages/index.tsx").as_deref(), Some("/"));
+    assert_eq!(
+        config.route("pages/blog/index.tsx").as_deref(),
+        Some("/blog")
+    );
+    assert_eq!(
+        config.route("src/pages/admin/users/[id].tsx").as_deref(),
+        Some("/admin/users/[id]")
+    );
+    assert_eq!(
+        config.route("app/(marketing)/about/page.tsx").as_deref(),
+        Some("/about")
+    );
+    assert_eq!(
+        config.route("app/admin/@modal/Dialog.tsx").as_deref(),
+        Some("/admin/Dialog")
+    );
+    assert_eq!(config.route("components/Header.tsx"), None);
+}
+
+#[test]
+fn route_overrides() {
+    let config: Config = serde_json::from_str(
+        r#"{
+            "excluded": ["session"],
+            "routes": {
+                "/admin/**": { "excluded": ["user", "session"] },
+                "/admin/legacy/**": { "enabled": false },
+                "/admin/legacy/keep": { "enabled": true }
+            }
+        }"#,
+    )
+    .unwrap();
+
+    let admin = config.route_config("/admin/users/[id]");
+    assert_eq!(admin.enabled, None);
+    assert_eq!(
+        admin.excluded,
+        Some(vec!["user".to_string(), "session".to_string()])
+    );
+
+    let legacy = config.route_config("/admin/legacy/report");
+    assert_eq!(legacy.enabled, Some(false));
+    assert!(legacy.excluded.is_some());
+
+    assert_eq!(
+        config.route_config("/admin/legacy/keep").enabled,
+        Some(true)
+    );
+
+    let public = config.route_config("/blog");
+    assert_eq!(public.enabled, None);
+    assert_eq!(public.excluded, None);
+}
+
+#[test]
+fn resolve_paths() {
+    let resolve = |path| dir_type(Config::default().resolve(Some("/repo/web"), Some(path)));
+
+    assert_eq!(resolve("/repo/web/pages/index.tsx"), Some(DirType::Page));
+    assert_eq!(resolve("/repo/web/src/app/page.tsx"), Some(DirType::App));
+    assert_eq!(resolve("/repo/web/lib/date.ts"), None);
+    assert_eq!(resolve("/repo/web/node_modules/ui/app/page.js"), None);
+    // sibling directory sharing a prefix with the project directory
+    assert_eq!(resolve("/repo/webapp/page.tsx"), None);
+    assert_eq!(resolve("/repo/webpages/index.tsx"), None);
+
+    let config = Config {
+        excluded: vec!["session".into()],
+        ..serde_json::from_str(r#"{ "routes": { "/admin/**": { "excluded": ["user"] } } }"#)
+            .unwrap()
+    };
+    assert_eq!(
+        config.resolve(Some("C:\\web"), Some("C:\\web\\pages\\admin\\[id].tsx")),
+        Some(ResolvedFile {
+            dir_type: DirType::Page,
+            route: Some("/admin/[id]".into()),
+            excluded: vec!["user".into()],
+        })
+    );
+    // the route override doesn't carry over to the next file
+    assert_eq!(
+        config
+            .resolve(Some("/web"), Some("/web/pages/index.tsx"))
+            .map(|file| file.excluded),
+        Some(vec!["session".into()])
+    );
+    assert_eq!(config.excluded, vec!["session".to_string()]);
+}
+
+#[test]
+fn resolve_skip_reasons() {
+    let config: Config = serde_json::from_str(
+        r#"{
+            "ignore": ["**/*.test.tsx"],
+            "routes": { "/legacy/**": { "enabled": false } }
+        }"#,
+    )
+    .unwrap();
+    let reason = |path| {
+        config
+            .resolve_or_skip(Some("/web"), Some(path))
+            .unwrap_err()
+    };
+
+    assert_eq!(
+        reason("/web/pages/index.test.tsx"),
+        "filtered out by `include` / `ignore`"
+    );
+    assert_eq!(
+        reason("/web/node_modules/react/index.js"),
+        "package `react` is not listed in `transpilePackages`"
+    );
+    assert_eq!(reason("/web/lib/date.ts"), "not a page or app router file");
+    assert_eq!(reason("/webapp/page.tsx"), "outside the project directory");
+    assert_eq!(
+        reason("/web/pages/legacy/report.tsx"),
+        "disabled for route `/legacy/report` by `routes`"
+    );
+}
+
+#[test]
+fn resolve_modes() {
+    let config = |mode, fallback| Config {
+        mode,
+        fallback,
+        ..Default::default()
+    };
+
+    // metadata missing
+    assert_eq!(dir_type(config(Mode::Auto, None).resolve(None, None)), None);
+    assert_eq!(
+        dir_type(config(Mode::Auto, Some(DirType::Page)).resolve(None, Some("stdin"))),
+        Some(DirType::Page)
+    );
+    assert_eq!(
+        dir_type(config(Mode::App, None).resolve(None, None)),
+        Some(DirType::App)
+    );
+
+    // forced
+    assert_eq!(
+        dir_type(
+            config(Mode::Page, None).resolve(Some("/web"), Some("/web/__tests__/page.test.js"))
+        ),
+        Some(DirType::Page)
+    );
+    assert_eq!(
+        dir_type(config(Mode::Off, None).resolve(Some("/web"), Some("/web/pages/index.tsx"))),
+        None
+    );
+}
+
+#[test]
+fn page_extensions() {
+    let config: Config =
+        serde_json::from_str(r#"{ "pageExtensions": ["page.tsx", "page.ts"] }"#).unwrap();
+
+    assert_eq!(config.dir_type("pages/index.page.tsx"), Some(DirType::Page));
+    assert_eq!(
+        config.dir_type("pages/api/user.page.ts"),
+        Some(DirType::Page)
+    );
+    assert_eq!(config.dir_type("pages/blog/Button.tsx"), None);
+    assert_eq!(config.dir_type("pages/blog/useDate.ts"), None);
+    assert_eq!(config.dir_type("app/Button.tsx"), Some(DirType::App));
+
+    assert_eq!(
+        config.route("pages/blog/[id].page.tsx").as_deref(),
+        Some("/blog/[id]")
+    );
+    assert_eq!(config.route("pages/index.page.tsx").as_deref(), Some("/"));
+
+    assert_eq!(Config::default().dir_type("pages/styles.module.css"), None);
+}
+
+#[test]
+fn transpile_packages() {
+    let config = Config {
+        transpile_packages: vec!["@acme/ui".into(), "charts".into()],
+        ..Default::default()
+    };
+    let resolve = |path| dir_type(config.resolve(Some("/web"), Some(path)));
+
+    assert_eq!(
+        resolve("/web/node_modules/@acme/ui/dist/Chart.js"),
+        Some(DirType::App)
+    );
+    assert_eq!(
+        resolve("/web/node_modules/.pnpm/charts@1.0.0/node_modules/charts/index.js"),
+        Some(DirType::App)
+    );
+    assert_eq!(resolve("/web/node_modules/@acme/utils/index.js"), None);
+    assert_eq!(resolve("/web/node_modules/@acme/ui-extra/index.js"), None);
+    assert_eq!(resolve("/web/node_modules/react/index.js"), None);
+}
+
+#[test]
+fn resolve_sections() {
+    let config: Config = serde_json::from_str(
+        r#"{
+            "pages": { "enabled": false },
+            "app": { "excluded": ["action"] },
+            "routes": {
+                "/legacy/**": { "enabled": true },
+                "/dashboard/**": { "excluded": ["onSelect"] }
+            }
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        config.resolve(Some("/web"), Some("/web/pages/index.tsx")),
+        None
+    );
+    assert_eq!(
+        dir_type(config.resolve(Some("/web"), Some("/web/pages/legacy/report.tsx"))),
+        Some(DirType::Page)
+    );
+    assert_eq!(
+        config
+            .resolve(Some("/web"), Some("/web/app/dashboard/page.tsx"))
+            .map(|file| file.excluded),
+        Some(vec!["onSelect".into()])
+    );
+    assert_eq!(
+        config
+            .resolve(Some("/web"), Some("/web/app/settings/page.tsx"))
+            .map(|file| file.excluded),
+        Some(vec!["action".into()])
+    );
+}
+
+#[test]
+fn parse_suggests_known_options() {
+    assert_eq!(
+        Config::parse(r#"{ "exclude": ["a"] }"#).unwrap_err(),
+        vec!["unknown option `exclude`, did you mean `excluded`?".to_string()]
+    );
+    assert_eq!(
+        Config::parse(
+            r#"{
+                "page_extensions": ["page.tsx"],
+                "app": { "directve": "data-sj" },
+                "routes": { "/admin/**": { "enable": false } },
+                "superjson": true
+            }"#
+        )
+        .unwrap_err(),
+        vec![
+            "unknown option `app.directve`, did you mean `directive`?".to_string(),
+            "unknown option `page_extensions`, did you mean `pageExtensions`?".to_string(),
+            "unknown option `routes./admin/**.enable`, did you mean `enabled`?".to_string(),
+            "unknown option `superjson`".to_string(),
+        ]
+    );
+    assert!(Config::parse(r#"{ "mode": "pages" }"#).is_err());
+    assert!(Config::parse("{}").is_ok());
+}
+
+#[test]
+fn schema_matches_config() {
+    let schema = config_schema();
+    let properties = schema["properties"].as_object().unwrap();
+
+    // every documented option deserializes with its documented default
+    for (key, property) in properties {
+        let options = serde_json::json!({ key: property["default"] }).to_string();
+        assert!(Config::parse(&options).is_ok(), "{options}");
+    }
+
+    // every option of `Config` is documented, nested sections included
+    fn assert_documented(schema: &serde_json::Value, defaults: &serde_json::Value, path: &str) {
+        let properties = schema["properties"].as_object().unwrap();
+
+        for (key, default) in defaults.as_object().unwrap() {
+            let key_path = format!("{path}{key}");
+            let property = properties
+                .get(key)
+                .unwrap_or_else(|| panic!("`{key_path}` is missing from the schema"));
+            assert_eq!(&property["default"], default, "{key_path}");
+
+            if property.get("properties").is_some() {
+                assert_documented(property, default, &format!("{key_path}."));
+            }
+        }
+        assert_eq!(
+            properties.len(),
+            defaults.as_object().unwrap().len(),
+            "{path}"
+        );
+    }
+
+    let defaults = serde_json::to_value(Config::default()).unwrap();
+    assert_documented(&schema, &defaults, "");
+}
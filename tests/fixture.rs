@@ -1,53 +1,174 @@
-use std::path::PathBuf;
-use swc_core::ecma::{
-    parser::{EsConfig, Syntax},
-    transforms::testing::{test_fixture, FixtureTestConfig},
-    visit::as_folder,
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+    sync::{Arc, RwLock},
 };
-use testing::fixture;
+use swc_core::{
+    common::{
+        chain,
+        errors::{Handler, HandlerFlags, HANDLER},
+        sync::Lrc,
+        Mark, SourceMap,
+    },
+    ecma::{
+        ast::Program,
+        parser::{EsConfig, Syntax},
+        transforms::{base::resolver, testing::test_fixture},
+        visit::{as_folder, Fold, FoldWith},
+    },
+};
+use swc_error_reporters::{
+    GraphicalReportHandler, GraphicalTheme, PrettyEmitter, PrettyEmitterConfig,
+};
+use testing::{fixture, NormalizedOutput};
+
+use next_superjson::{app::transform_app, page::transform_page, Config, DirType, ResolvedFile};
+
+// per-fixture options live in config.json next to code.js
+fn fixture_config(input: &Path, dir_type: DirType) -> (Config, ResolvedFile) {
+    let (config, route) = match fs::read_to_string(input.with_file_name("config.json")) {
+        Ok(config) => {
+            let mut options: serde_json::Value = serde_json::from_str(&config).unwrap();
+            // stands in for the file path hosts pass, which `resolve` turns into a route
+            let route = options.as_object_mut().unwrap().remove("route");
+
+            (
+                serde_json::from_value(options).unwrap(),
+                route.map(|route| route.as_str().unwrap().to_string()),
+            )
+        }
+        Err(_) => (
+            Config {
+                excluded: vec!["smth".to_string()],
+                ..Default::default()
+            },
+            None,
+        ),
+    };
+
+    let file = config.resolve_route(dir_type, route).unwrap();
+    (config, file)
+}
+
+// `test_fixture` drops warnings, so diagnostics are collected here instead
+// and compared to output.stderr
+#[derive(Clone, Default)]
+struct Diagnostics(Arc<RwLock<String>>);
+
+impl fmt::Write for Diagnostics {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0.write().unwrap().push_str(s);
+        Ok(())
+    }
+}
 
-use next_superjson::{app::transform_app, page::transform_page, Config};
+impl Diagnostics {
+    fn capture<F: Fold>(&self, cm: Lrc<SourceMap>, inner: F) -> impl Fold {
+        let emitter = PrettyEmitter::new(
+            cm,
+            Box::new(self.clone()),
+            GraphicalReportHandler::default().with_theme(GraphicalTheme::none()),
+            PrettyEmitterConfig {
+                skip_filename: false,
+            },
+        );
+        let handler = Handler::with_emitter_and_flags(
+            Box::new(emitter),
+            HandlerFlags {
+                can_emit_warnings: true,
+                ..Default::default()
+            },
+        );
+
+        Capture { handler, inner }
+    }
+
+    fn compare(self, output: &Path) {
+        let stderr = output.with_extension("stderr");
+        let diagnostics = self.0.read().unwrap().clone();
+
+        if stderr.exists() {
+            NormalizedOutput::from(diagnostics)
+                .compare_to_file(stderr)
+                .unwrap();
+        } else {
+            assert!(diagnostics.is_empty(), "stderr: {diagnostics}");
+        }
+    }
+}
+
+struct Capture<F> {
+    handler: Handler,
+    inner: F,
+}
+
+impl<F: Fold> Fold for Capture<F> {
+    fn fold_program(&mut self, program: Program) -> Program {
+        HANDLER.set(&self.handler, || program.fold_with(&mut self.inner))
+    }
+}
 
 #[fixture("tests/fixture/page/**/code.js")]
 fn fixture_page(input: PathBuf) {
     let output = input.with_file_name("output.js");
+    let (config, file) = fixture_config(&input, DirType::Page);
+    let diagnostics = Diagnostics::default();
 
     test_fixture(
         Syntax::Es(EsConfig {
             jsx: true,
             ..Default::default()
         }),
-        &|_| {
-            as_folder(transform_page(Config {
-                excluded: vec!["smth".to_string()],
-            }))
+        // hosts run the resolver before plugins
+        &|t| {
+            diagnostics.capture(
+                t.cm.clone(),
+                chain!(
+                    resolver(Mark::new(), Mark::new(), false),
+                    as_folder(transform_page(
+                        config.clone(),
+                        file.clone(),
+                        t.comments.clone(),
+                    ))
+                ),
+            )
         },
         &input,
         &output,
-        FixtureTestConfig {
-            ..Default::default()
-        },
+        Default::default(),
     );
+
+    diagnostics.compare(&output);
 }
 
 #[fixture("tests/fixture/app/**/code.js")]
 fn fixture_app(input: PathBuf) {
     let output = input.with_file_name("output.js");
+    let (config, file) = fixture_config(&input, DirType::App);
+    let diagnostics = Diagnostics::default();
 
     test_fixture(
         Syntax::Es(EsConfig {
             jsx: true,
             ..Default::default()
         }),
-        &|_| {
-            as_folder(transform_app(Config {
-                excluded: vec!["smth".to_string()],
-            }))
+        &|t| {
+            diagnostics.capture(
+                t.cm.clone(),
+                chain!(
+                    resolver(Mark::new(), Mark::new(), false),
+                    as_folder(transform_app(
+                        config.clone(),
+                        file.clone(),
+                        t.comments.clone(),
+                    ))
+                ),
+            )
         },
         &input,
         &output,
-        FixtureTestConfig {
-            ..Default::default()
-        },
+        Default::default(),
     );
+
+    diagnostics.compare(&output);
 }
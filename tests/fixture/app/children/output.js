@@ -7,7 +7,7 @@ export default function Page() {
     return <SuperJSONComponent props={serialize({
         date: date,
         ...rest
-    })} component={ClientComponent}>
+    })} component={ClientComponent} displayName="SuperJSON(ClientComponent)">
 
       <p>children</p>
 
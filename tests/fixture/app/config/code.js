@@ -0,0 +1,13 @@
+import ClientComponent from "./ClientComponent";
+import { save } from "./actions";
+
+export default function Page() {
+  const date = new Date();
+
+  return (
+    <>
+      <ClientComponent date={date} data-superjson />
+      <ClientComponent date={date} action={save} data-sj />
+    </>
+  );
+}
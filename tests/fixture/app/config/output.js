@@ -0,0 +1,19 @@
+import { serialize } from "next-superjson-plugin/tools";
+import SuperJSONComponent from "next-superjson-plugin/client";
+import ClientComponent from "./ClientComponent";
+import { save } from "./actions";
+export default function Page() {
+    const date = new Date();
+    return <>
+
+      <ClientComponent date={date} data-superjson/>
+
+      <SuperJSONComponent props={serialize({
+        date: date,
+        action: save
+    }, [
+        "action"
+    ])} component={ClientComponent} displayName="SuperJSON(ClientComponent)"/>
+
+    </>;
+}
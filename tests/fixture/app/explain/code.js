@@ -0,0 +1,6 @@
+import ClientComponent from "./ClientComponent";
+
+export default function Page() {
+  const date = new Date();
+  return <ClientComponent date={date} data-superjson />;
+}
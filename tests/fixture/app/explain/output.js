@@ -0,0 +1,9 @@
+import { serialize } from "next-superjson-plugin/tools";
+import SuperJSONComponent from "next-superjson-plugin/client";
+import ClientComponent from "./ClientComponent";
+export default function Page() {
+    const date = new Date();
+    return <SuperJSONComponent props={serialize({
+        date: date
+    })} component={ClientComponent} displayName="SuperJSON(ClientComponent)"/>;
+}
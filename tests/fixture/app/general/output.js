@@ -15,6 +15,7 @@ export default function Page() {
           ...rest,
         })}
         component={ClientComponent}
+        displayName="SuperJSON(ClientComponent)"
       />
     </>;
 }
@@ -15,6 +15,7 @@ export default function Page() {
           ...rest,
         })}
         component={Client.Component}
+        displayName="SuperJSON(Client.Component)"
       />
     </>;
 }
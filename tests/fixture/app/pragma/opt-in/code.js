@@ -0,0 +1,6 @@
+/* @superjson */
+import ClientComponent from "./ClientComponent";
+
+export default function Page() {
+  return <ClientComponent date={new Date()} data-superjson />;
+}
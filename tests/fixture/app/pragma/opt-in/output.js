@@ -0,0 +1,8 @@
+/* @superjson */ import { serialize } from "next-superjson-plugin/tools";
+import SuperJSONComponent from "next-superjson-plugin/client";
+import ClientComponent from "./ClientComponent";
+export default function Page() {
+    return <SuperJSONComponent props={serialize({
+        date: new Date()
+    })} component={ClientComponent} displayName="SuperJSON(ClientComponent)"/>;
+}
@@ -0,0 +1,18 @@
+import { serialize } from "@acme/superjson-runtime/server";
+import SuperJSONComponent from "@acme/superjson-runtime/client";
+import ServerComponent from "./ServerComponent";
+import ClientComponent from "./ClientComponent";
+export default function Page() {
+    const rest = {};
+    const date = new Date();
+    return <>
+
+      <ServerComponent date={date}/>
+
+      <SuperJSONComponent props={serialize({
+        date: date,
+        ...rest
+    })} component={ClientComponent} displayName="SuperJSON(ClientComponent)"/>
+
+    </>;
+}
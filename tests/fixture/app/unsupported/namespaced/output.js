@@ -0,0 +1,4 @@
+export default function Page() {
+    const date = new Date();
+    return <svg:text date={date} data-superjson/>;
+}
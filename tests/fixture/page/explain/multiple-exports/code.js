@@ -0,0 +1,7 @@
+export const getStaticProps = () => {};
+
+export const getServerSideProps = () => {};
+
+export default function Page() {
+  return <></>;
+}
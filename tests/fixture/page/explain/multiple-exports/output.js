@@ -0,0 +1,5 @@
+export const getStaticProps = ()=>{};
+export const getServerSideProps = ()=>{};
+export default function Page() {
+    return <></>;
+}
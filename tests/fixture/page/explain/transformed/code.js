@@ -0,0 +1,7 @@
+export function getServerSideProps() {
+  return { props: { date: new Date() } };
+}
+
+export default function Page({ date }) {
+  return <p>{date.toISOString()}</p>;
+}
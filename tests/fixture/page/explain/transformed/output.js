@@ -0,0 +1,16 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+function getServerSideProps() {
+    return {
+        props: {
+            date: new Date()
+        }
+    };
+}
+const _NEXT_SUPERJSON_SSG_PROPS = _withSuperJSONProps(getServerSideProps);
+export { _NEXT_SUPERJSON_SSG_PROPS as getServerSideProps };
+function Page({ date }) {
+    return <p>{date.toISOString()}</p>;
+}
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
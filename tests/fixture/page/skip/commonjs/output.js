@@ -0,0 +1,9 @@
+const { helper } = require("../lib/helper");
+exports.getServerSideProps = async ()=>{
+    return {
+        props: {
+            date: new Date()
+        }
+    };
+};
+exports.helper = helper;
@@ -0,0 +1,6 @@
+export const { a, b } = helpers();
+export const [x] = list();
+
+export default function Page() {
+  return <></>;
+}
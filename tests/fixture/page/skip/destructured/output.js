@@ -0,0 +1,5 @@
+export const { a, b } = helpers();
+export const [x] = list();
+export default function Page() {
+    return <></>;
+}
@@ -1 +0,0 @@
-export { getServerSideProps, default } from 'source'
\ No newline at end of file
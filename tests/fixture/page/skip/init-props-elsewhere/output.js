@@ -0,0 +1,11 @@
+import Other from "../components/Other";
+function Helper() {
+    return <></>;
+}
+Helper.getInitialProps = async ()=>({
+        date: new Date()
+    });
+console.log(Other.getInitialProps);
+export default function Page() {
+    return <Helper/>;
+}
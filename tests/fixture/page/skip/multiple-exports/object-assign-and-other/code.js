@@ -0,0 +1,15 @@
+function Page() {
+  return <div>Page</div>;
+}
+
+Object.assign(Page, {
+  getInitialProps() {
+    return {};
+  },
+});
+
+export const getServerSideProps = () => {
+  return { props: {} };
+};
+
+export default Page;
@@ -0,0 +1,14 @@
+function Page() {
+    return <div>Page</div>;
+}
+Object.assign(Page, {
+    getInitialProps () {
+        return {};
+    }
+});
+export const getServerSideProps = ()=>{
+    return {
+        props: {}
+    };
+};
+export default Page;
@@ -0,0 +1,11 @@
+const load = async ()=>{
+    return {
+        props: {
+            date: new Date()
+        }
+    };
+};
+export const getServerSideProps = load, getStaticProps = load;
+export default function Page({ date }) {
+    return <p>{date.toISOString()}</p>;
+}
@@ -0,0 +1,7 @@
+import * as data from "./data";
+
+export const { getServerSideProps, getStaticProps } = data;
+
+export default function Page({ date }) {
+  return <p>{date.toISOString()}</p>;
+}
@@ -0,0 +1,5 @@
+import * as data from "./data";
+export const { getServerSideProps, getStaticProps } = data;
+export default function Page({ date }) {
+    return <p>{date.toISOString()}</p>;
+}
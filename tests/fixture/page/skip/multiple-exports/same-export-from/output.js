@@ -0,0 +1,4 @@
+export { getServerSideProps, getStaticProps } from "./data";
+export default function Page({ date }) {
+    return <p>{date.toISOString()}</p>;
+}
@@ -0,0 +1,9 @@
+async function load() {
+  return { props: { date: new Date() } };
+}
+
+export { load as getStaticProps, load as getServerSideProps };
+
+export default function Page({ date }) {
+  return <p>{date.toISOString()}</p>;
+}
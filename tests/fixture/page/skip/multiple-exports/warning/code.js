@@ -0,0 +1,11 @@
+export default function Page({ date }) {
+  return <p>{date.toISOString()}</p>
+}
+
+export const getStaticProps = async () => {
+  return { props: { date: new Date() } }
+}
+
+export const getServerSideProps = async () => {
+  return { props: { date: new Date() } }
+}
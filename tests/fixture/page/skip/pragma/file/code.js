@@ -0,0 +1,6 @@
+/* @superjson-disable */
+export const getServerSideProps = () => {};
+
+export default function Page() {
+  return <></>;
+}
@@ -0,0 +1,4 @@
+/* @superjson-disable */ export const getServerSideProps = ()=>{};
+export default function Page() {
+    return <></>;
+}
@@ -0,0 +1,10 @@
+import { db } from "../lib/db";
+
+/** @superjson-disable */
+export async function getServerSideProps() {
+  return { props: { posts: await db.posts() } };
+}
+
+export default function Page() {
+  return <></>;
+}
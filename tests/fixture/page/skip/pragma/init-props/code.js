@@ -0,0 +1,10 @@
+function Page() {
+  return <></>;
+}
+
+// @superjson-disable
+Page.getInitialProps = async () => {
+  return {};
+};
+
+export default Page;
@@ -0,0 +1,8 @@
+function Page() {
+    return <></>;
+}
+// @superjson-disable
+Page.getInitialProps = async ()=>{
+    return {};
+};
+export default Page;
@@ -0,0 +1,4 @@
+export const getServerSideProps = ()=>{};
+export default function Page() {
+    return <></>;
+}
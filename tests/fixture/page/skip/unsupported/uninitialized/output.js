@@ -0,0 +1,5 @@
+export let getServerSideProps;
+
+export default function Page() {
+  return <></>;
+}
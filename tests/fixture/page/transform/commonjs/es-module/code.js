@@ -0,0 +1,19 @@
+"use strict";
+
+Object.defineProperty(exports, "__esModule", {
+  value: true
+});
+exports.default = exports.getServerSideProps = void 0;
+var _react = _interopRequireDefault(require("react"));
+function _interopRequireDefault(obj) {
+  return obj && obj.__esModule ? obj : { default: obj };
+}
+function Page({ date }) {
+  return _react.default.createElement("p", null, date.toISOString());
+}
+const getServerSideProps = async () => {
+  return { props: { date: new Date() } };
+};
+exports.getServerSideProps = getServerSideProps;
+var _default = Page;
+exports.default = _default;
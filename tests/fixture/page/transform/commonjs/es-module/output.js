@@ -0,0 +1,29 @@
+"use strict";
+const { withSuperJSONPage: _withSuperJSONPage } = require("next-superjson-plugin/tools");
+const { withSuperJSONProps: _withSuperJSONProps } = require("next-superjson-plugin/tools");
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+exports.default = exports.getServerSideProps = void 0;
+var _react = _interopRequireDefault(require("react"));
+function _interopRequireDefault(obj) {
+    return obj && obj.__esModule ? obj : {
+        default: obj
+    };
+}
+function Page({ date }) {
+    return _react.default.createElement("p", null, date.toISOString());
+}
+const getServerSideProps = async ()=>{
+    return {
+        props: {
+            date: new Date()
+        }
+    };
+};
+exports.getServerSideProps = _withSuperJSONProps(getServerSideProps, [
+    "smth"
+]);
+var _default = Page;
+const NextSuperJSONPage = _withSuperJSONPage(_default);
+exports.default = NextSuperJSONPage;
@@ -0,0 +1,9 @@
+const withLayout = require("../components/withLayout");
+
+function Page({ date }) {
+  return <div>{date.getDate()}</div>;
+}
+
+Page.getInitialProps = async () => ({ date: new Date() });
+
+module.exports = withLayout(Page);
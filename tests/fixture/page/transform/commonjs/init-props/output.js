@@ -0,0 +1,13 @@
+const { withSuperJSONPage: _withSuperJSONPage } = require("next-superjson-plugin/tools");
+const { withSuperJSONInitProps: _withSuperJSONInitProps } = require("next-superjson-plugin/tools");
+const withLayout = require("../components/withLayout");
+function Page({ date }) {
+    return <div>{date.getDate()}</div>;
+}
+Page.getInitialProps = _withSuperJSONInitProps(async ()=>({
+        date: new Date()
+    }), [
+    "smth"
+]);
+const NextSuperJSONPage = _withSuperJSONPage(withLayout(Page));
+module.exports = NextSuperJSONPage;
@@ -0,0 +1,7 @@
+function Page({ date }) {
+  return <div>{date.getDate()}</div>;
+}
+
+module.exports = Page;
+
+Page.getInitialProps = async () => ({ date: new Date() });
@@ -0,0 +1,13 @@
+"use strict";
+
+const React = require("react");
+
+function Page({ date }) {
+  return React.createElement("p", null, date.toISOString());
+}
+
+module.exports = Page;
+
+module.exports.getServerSideProps = async () => {
+  return { props: { date: new Date() } };
+};
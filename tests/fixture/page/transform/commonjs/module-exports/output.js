@@ -0,0 +1,18 @@
+"use strict";
+const { withSuperJSONPage: _withSuperJSONPage } = require("next-superjson-plugin/tools");
+const { withSuperJSONProps: _withSuperJSONProps } = require("next-superjson-plugin/tools");
+const React = require("react");
+function Page({ date }) {
+    return React.createElement("p", null, date.toISOString());
+}
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+module.exports = NextSuperJSONPage;
+module.exports.getServerSideProps = _withSuperJSONProps(async ()=>{
+    return {
+        props: {
+            date: new Date()
+        }
+    };
+}, [
+    "smth"
+]);
@@ -0,0 +1,7 @@
+export const { load } = createHandlers();
+
+export { load as getServerSideProps };
+
+export default function Page() {
+  return <></>;
+}
@@ -0,0 +1,12 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+const { load: _NEXT_SUPERJSON_DESTRUCTURED_PROPS } = createHandlers();
+export const load = _withSuperJSONProps(_NEXT_SUPERJSON_DESTRUCTURED_PROPS, [
+    "smth"
+]);
+export { load as getServerSideProps };
+function Page() {
+    return <></>;
+}
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
@@ -0,0 +1,5 @@
+export const [getStaticProps, getStaticPaths] = createStaticHandlers();
+
+export default function Page() {
+  return <></>;
+}
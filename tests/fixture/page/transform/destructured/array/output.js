@@ -0,0 +1,12 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+const [_NEXT_SUPERJSON_DESTRUCTURED_PROPS, getStaticPaths] = createStaticHandlers();
+export { getStaticPaths };
+export const getStaticProps = _withSuperJSONProps(_NEXT_SUPERJSON_DESTRUCTURED_PROPS, [
+    "smth"
+]);
+function Page() {
+    return <></>;
+}
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
@@ -0,0 +1,7 @@
+import { createHandlers } from "../lib/handlers";
+
+export const { getServerSideProps, config = {} } = createHandlers();
+
+export default function Page() {
+  return <></>;
+}
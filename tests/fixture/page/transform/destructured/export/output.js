@@ -0,0 +1,13 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+import { createHandlers } from "../lib/handlers";
+const { getServerSideProps: _NEXT_SUPERJSON_DESTRUCTURED_PROPS, config = {} } = createHandlers();
+export { config };
+export const getServerSideProps = _withSuperJSONProps(_NEXT_SUPERJSON_DESTRUCTURED_PROPS, [
+    "smth"
+]);
+function Page() {
+    return <></>;
+}
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
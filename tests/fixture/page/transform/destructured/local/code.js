@@ -0,0 +1,9 @@
+import { createHandlers } from "../lib/handlers";
+
+const { getServerSideProps = async () => ({ props: {} }), revalidate } = createHandlers();
+
+export { getServerSideProps, revalidate };
+
+export default function Page() {
+  return <></>;
+}
@@ -0,0 +1,15 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+import { createHandlers } from "../lib/handlers";
+const { getServerSideProps: _NEXT_SUPERJSON_DESTRUCTURED_PROPS = async ()=>({
+        props: {}
+    }), revalidate } = createHandlers();
+const getServerSideProps = _withSuperJSONProps(_NEXT_SUPERJSON_DESTRUCTURED_PROPS, [
+    "smth"
+]);
+export { getServerSideProps as getServerSideProps, revalidate };
+function Page() {
+    return <></>;
+}
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
@@ -0,0 +1,15 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+export const getServerSideProps = _withSuperJSONProps(async ()=>{}, [
+    "session"
+], {
+    page: "/blog/[slug]"
+});
+const NextSuperJSONPageComponent = ()=>{
+    return <></>;
+};
+const NextSuperJSONPage = _withSuperJSONPage(NextSuperJSONPageComponent, {
+    page: "/blog/[slug]"
+});
+NextSuperJSONPage.displayName = "SuperJSON(BlogSlugPage)";
+export default NextSuperJSONPage;
@@ -0,0 +1,9 @@
+export { Page as default };
+
+const Page = ({ date }) => <p>{date.toISOString()}</p>;
+
+Page.getLayout = (page) => page;
+
+export const getServerSideProps = async () => {
+  return { props: { date: new Date() } };
+};
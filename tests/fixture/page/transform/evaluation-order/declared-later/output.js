@@ -0,0 +1,15 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+const Page = ({ date })=><p>{date.toISOString()}</p>;
+Page.getLayout = (page)=>page;
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
+export const getServerSideProps = _withSuperJSONProps(async ()=>{
+    return {
+        props: {
+            date: new Date()
+        }
+    };
+}, [
+    "smth"
+]);
@@ -0,0 +1,14 @@
+import { register } from "../registry";
+
+function Page({ date }) {
+  return <p>{date.toISOString()}</p>;
+}
+
+export default Page;
+
+register(Page);
+Page.getLayout = (page) => page;
+
+export const getServerSideProps = async () => {
+  return { props: { date: new Date() } };
+};
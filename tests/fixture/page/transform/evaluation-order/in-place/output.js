@@ -0,0 +1,19 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+import { register } from "../registry";
+function Page({ date }) {
+    return <p>{date.toISOString()}</p>;
+}
+register(Page);
+Page.getLayout = (page)=>page;
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
+export const getServerSideProps = _withSuperJSONProps(async ()=>{
+    return {
+        props: {
+            date: new Date()
+        }
+    };
+}, [
+    "smth"
+]);
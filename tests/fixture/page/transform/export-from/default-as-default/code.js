@@ -0,0 +1,5 @@
+export { default as default, Header } from './Impl'
+
+export const getStaticProps = async () => {
+  return { props: { date: new Date() } }
+}
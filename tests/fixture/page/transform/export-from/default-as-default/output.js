@@ -0,0 +1,15 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+import { default as _NEXT_SUPERJSON_IMPORTED_PAGE } from './Impl';
+export { Header } from './Impl';
+const NextSuperJSONPage = _withSuperJSONPage(_NEXT_SUPERJSON_IMPORTED_PAGE);
+export default NextSuperJSONPage;
+export const getStaticProps = _withSuperJSONProps(async ()=>{
+    return {
+        props: {
+            date: new Date()
+        }
+    };
+}, [
+    "smth"
+]);
@@ -0,0 +1,5 @@
+export { default } from './Impl'
+
+export async function getServerSideProps() {
+  return { props: { date: new Date() } }
+}
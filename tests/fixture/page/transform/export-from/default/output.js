@@ -0,0 +1,16 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+import { default as _NEXT_SUPERJSON_IMPORTED_PAGE } from './Impl';
+const NextSuperJSONPage = _withSuperJSONPage(_NEXT_SUPERJSON_IMPORTED_PAGE);
+export default NextSuperJSONPage;
+async function getServerSideProps() {
+    return {
+        props: {
+            date: new Date()
+        }
+    };
+}
+const _NEXT_SUPERJSON_SSG_PROPS = _withSuperJSONProps(getServerSideProps, [
+    "smth"
+]);
+export { _NEXT_SUPERJSON_SSG_PROPS as getServerSideProps };
@@ -0,0 +1,7 @@
+import { getServerSideProps as g } from "../shared/data";
+
+export { g as getServerSideProps };
+
+export default function Page({ date }) {
+  return <p>{date.toISOString()}</p>;
+}
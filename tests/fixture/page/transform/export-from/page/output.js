@@ -1,4 +1,10 @@
 import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+import { getServerSideProps as _NEXT_SUPERJSON_IMPORTED_PROPS } from 'source';
+const _NEXT_SUPERJSON_SSG_PROPS = _withSuperJSONProps(_NEXT_SUPERJSON_IMPORTED_PROPS, [
+    "smth"
+]);
+export { _NEXT_SUPERJSON_SSG_PROPS as getServerSideProps };
 import { foo as _NEXT_SUPERJSON_IMPORTED_PAGE } from 'source';
-export { getServerSideProps } from 'source';
-export default _withSuperJSONPage(_NEXT_SUPERJSON_IMPORTED_PAGE);
\ No newline at end of file
+const NextSuperJSONPage = _withSuperJSONPage(_NEXT_SUPERJSON_IMPORTED_PAGE);
+export default NextSuperJSONPage;
@@ -0,0 +1 @@
+export { getStaticProps, Header, default } from './Impl'
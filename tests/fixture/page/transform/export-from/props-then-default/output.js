@@ -0,0 +1,11 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+import { getStaticProps as _NEXT_SUPERJSON_IMPORTED_PROPS } from './Impl';
+const _NEXT_SUPERJSON_SSG_PROPS = _withSuperJSONProps(_NEXT_SUPERJSON_IMPORTED_PROPS, [
+    "smth"
+]);
+export { _NEXT_SUPERJSON_SSG_PROPS as getStaticProps };
+import { default as _NEXT_SUPERJSON_IMPORTED_PAGE } from './Impl';
+export { Header } from './Impl';
+const NextSuperJSONPage = _withSuperJSONPage(_NEXT_SUPERJSON_IMPORTED_PAGE);
+export default NextSuperJSONPage;
@@ -1,7 +1,10 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
 import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
 import { foo as _NEXT_SUPERJSON_IMPORTED_PROPS } from 'source';
 const _NEXT_SUPERJSON_SSG_PROPS = _withSuperJSONProps(_NEXT_SUPERJSON_IMPORTED_PROPS, [
     "smth"
 ]);
 export { _NEXT_SUPERJSON_SSG_PROPS as getServerSideProps };
-export { default } from 'source';
\ No newline at end of file
+import { default as _NEXT_SUPERJSON_IMPORTED_PAGE } from 'source';
+const NextSuperJSONPage = _withSuperJSONPage(_NEXT_SUPERJSON_IMPORTED_PAGE);
+export default NextSuperJSONPage;
@@ -0,0 +1,5 @@
+import Page from './Page'
+
+export { getServerSideProps } from '../shared/data'
+
+export default Page
@@ -0,0 +1,10 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+import Page from './Page';
+import { getServerSideProps as _NEXT_SUPERJSON_IMPORTED_PROPS } from '../shared/data';
+const _NEXT_SUPERJSON_SSG_PROPS = _withSuperJSONProps(_NEXT_SUPERJSON_IMPORTED_PROPS, [
+    "smth"
+]);
+export { _NEXT_SUPERJSON_SSG_PROPS as getServerSideProps };
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
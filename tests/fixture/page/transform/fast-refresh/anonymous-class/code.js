@@ -0,0 +1,9 @@
+export default class extends React.Component {
+  static getInitialProps() {
+    return { date: new Date() };
+  }
+
+  render() {
+    return <p>{this.props.date.toISOString()}</p>;
+  }
+}
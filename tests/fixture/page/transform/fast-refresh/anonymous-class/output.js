@@ -0,0 +1,17 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONInitProps as _withSuperJSONInitProps } from "next-superjson-plugin/tools";
+class NextSuperJSONPageComponent extends React.Component {
+    static getInitialProps = _withSuperJSONInitProps(function() {
+        return {
+            date: new Date()
+        };
+    }, [
+        "smth"
+    ]);
+    render() {
+        return <p>{this.props.date.toISOString()}</p>;
+    }
+}
+const NextSuperJSONPage = _withSuperJSONPage(NextSuperJSONPageComponent);
+NextSuperJSONPage.displayName = "SuperJSON(Page)";
+export default NextSuperJSONPage;
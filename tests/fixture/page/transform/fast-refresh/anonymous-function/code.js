@@ -0,0 +1,7 @@
+export default function ({ date }) {
+  return <p>{date.toISOString()}</p>;
+}
+
+export const getServerSideProps = async () => {
+  return { props: { date: new Date() } };
+};
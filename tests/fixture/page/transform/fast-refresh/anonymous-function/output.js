@@ -0,0 +1,17 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+function NextSuperJSONPageComponent({ date }) {
+    return <p>{date.toISOString()}</p>;
+}
+const NextSuperJSONPage = _withSuperJSONPage(NextSuperJSONPageComponent);
+NextSuperJSONPage.displayName = "SuperJSON(Page)";
+export default NextSuperJSONPage;
+export const getServerSideProps = _withSuperJSONProps(async ()=>{
+    return {
+        props: {
+            date: new Date()
+        }
+    };
+}, [
+    "smth"
+]);
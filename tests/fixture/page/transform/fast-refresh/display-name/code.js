@@ -0,0 +1,11 @@
+function Page({ date }) {
+  return <p>{date.toISOString()}</p>;
+}
+
+Page.displayName = "BlogPost";
+
+export default Page;
+
+export const getServerSideProps = async () => {
+  return { props: { date: new Date() } };
+};
@@ -0,0 +1,17 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+function Page({ date }) {
+    return <p>{date.toISOString()}</p>;
+}
+Page.displayName = "BlogPost";
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
+export const getServerSideProps = _withSuperJSONProps(async ()=>{
+    return {
+        props: {
+            date: new Date()
+        }
+    };
+}, [
+    "smth"
+]);
@@ -3,6 +3,9 @@ import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin
 export const getServerSideProps = _withSuperJSONProps(async () => {}, [
     "smth"
 ]);
-export default _withSuperJSONPage(() => {
+const NextSuperJSONPageComponent = () => {
     return <></>;
-});
+};
+const NextSuperJSONPage = _withSuperJSONPage(NextSuperJSONPageComponent);
+NextSuperJSONPage.displayName = "SuperJSON(Page)";
+export default NextSuperJSONPage;
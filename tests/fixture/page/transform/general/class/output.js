@@ -1,11 +1,14 @@
 import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
 import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
-export const getServerSideProps = _withSuperJSONProps(async function() {}, [
+async function getServerSideProps() {}
+const _NEXT_SUPERJSON_SSG_PROPS = _withSuperJSONProps(getServerSideProps, [
     "smth"
 ]);
+export { _NEXT_SUPERJSON_SSG_PROPS as getServerSideProps };
 class Page {
     render() {
         return <></>;
     }
 }
-export default _withSuperJSONPage(Page);
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
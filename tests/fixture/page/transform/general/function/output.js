@@ -1,9 +1,12 @@
 import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
 import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
-export const getServerSideProps = _withSuperJSONProps(async function () {}, [
+async function getServerSideProps() {}
+const _NEXT_SUPERJSON_SSG_PROPS = _withSuperJSONProps(getServerSideProps, [
   "smth",
 ]);
+export { _NEXT_SUPERJSON_SSG_PROPS as getServerSideProps };
 function Page() {
   return <></>;
 }
-export default _withSuperJSONPage(Page);
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
@@ -14,4 +14,5 @@ class Page extends React.Component {
   }
 }
 
-export default _withSuperJSONPage(Page)
\ No newline at end of file
+const NextSuperJSONPage = _withSuperJSONPage(Page)
+export default NextSuperJSONPage
\ No newline at end of file
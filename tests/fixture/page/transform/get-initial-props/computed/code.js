@@ -0,0 +1,7 @@
+export default function Page({ date }) {
+  return <div>{date.getDate()}</div>;
+}
+
+Page["getInitialProps"] = async () => {
+  return { date: new Date() };
+};
@@ -0,0 +1,14 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONInitProps as _withSuperJSONInitProps } from "next-superjson-plugin/tools";
+function Page({ date }) {
+    return <div>{date.getDate()}</div>;
+}
+Page["getInitialProps"] = _withSuperJSONInitProps(async ()=>{
+    return {
+        date: new Date()
+    };
+}, [
+    "smth"
+]);
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
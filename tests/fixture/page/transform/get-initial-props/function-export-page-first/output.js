@@ -11,4 +11,5 @@ Page.getInitialProps = _withSuperJSONInitProps(() => {
   }
 }, ["smth"]);
 
-export default _withSuperJSONPage(Page);
\ No newline at end of file
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
\ No newline at end of file
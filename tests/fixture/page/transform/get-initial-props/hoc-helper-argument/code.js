@@ -0,0 +1,21 @@
+import withLayout from "../components/withLayout";
+
+function Layout({ children }) {
+  return <main>{children}</main>;
+}
+
+Layout.getInitialProps = () => {
+  return { title: "" };
+};
+
+function Page({ date }) {
+  return <div>{date.getDate()}</div>;
+}
+
+Page.getInitialProps = () => {
+  return {
+    date: new Date(),
+  };
+};
+
+export default withLayout(Page, Layout);
@@ -0,0 +1,23 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONInitProps as _withSuperJSONInitProps } from "next-superjson-plugin/tools";
+import withLayout from "../components/withLayout";
+function Layout({ children }) {
+    return <main>{children}</main>;
+}
+Layout.getInitialProps = ()=>{
+    return {
+        title: ""
+    };
+};
+function Page({ date }) {
+    return <div>{date.getDate()}</div>;
+}
+Page.getInitialProps = _withSuperJSONInitProps(()=>{
+    return {
+        date: new Date()
+    };
+}, [
+    "smth"
+]);
+const NextSuperJSONPage = _withSuperJSONPage(withLayout(Page, Layout));
+export default NextSuperJSONPage;
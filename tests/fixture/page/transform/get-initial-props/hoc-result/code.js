@@ -0,0 +1,13 @@
+import withLayout from "../components/withLayout";
+
+function Page({ date }) {
+  return <div>{date.getDate()}</div>;
+}
+
+const Wrapped = withLayout(Page);
+
+Wrapped.getInitialProps = async () => {
+  return { date: new Date() };
+};
+
+export default Wrapped;
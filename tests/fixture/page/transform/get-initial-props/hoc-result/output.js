@@ -0,0 +1,16 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONInitProps as _withSuperJSONInitProps } from "next-superjson-plugin/tools";
+import withLayout from "../components/withLayout";
+function Page({ date }) {
+    return <div>{date.getDate()}</div>;
+}
+const Wrapped = withLayout(Page);
+Wrapped.getInitialProps = _withSuperJSONInitProps(async ()=>{
+    return {
+        date: new Date()
+    };
+}, [
+    "smth"
+]);
+const NextSuperJSONPage = _withSuperJSONPage(Wrapped);
+export default NextSuperJSONPage;
@@ -0,0 +1,13 @@
+import withLayout from "../components/withLayout";
+
+function Page({ date }) {
+  return <div>{date.getDate()}</div>;
+}
+
+Page.getInitialProps = () => {
+  return {
+    date: new Date(),
+  };
+};
+
+export default withLayout(Page);
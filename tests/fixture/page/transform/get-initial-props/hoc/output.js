@@ -0,0 +1,15 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONInitProps as _withSuperJSONInitProps } from "next-superjson-plugin/tools";
+import withLayout from "../components/withLayout";
+function Page({ date }) {
+    return <div>{date.getDate()}</div>;
+}
+Page.getInitialProps = _withSuperJSONInitProps(()=>{
+    return {
+        date: new Date()
+    };
+}, [
+    "smth"
+]);
+const NextSuperJSONPage = _withSuperJSONPage(withLayout(Page));
+export default NextSuperJSONPage;
@@ -0,0 +1,9 @@
+export default function Page({ date }) {
+  return <div>{date.getDate()}</div>;
+}
+
+Object.assign(Page, {
+  getLayout: (page) => page,
+});
+
+Page.getInitialProps = async () => ({ date: new Date() });
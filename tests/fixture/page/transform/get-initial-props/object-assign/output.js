@@ -0,0 +1,15 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONInitProps as _withSuperJSONInitProps } from "next-superjson-plugin/tools";
+function Page({ date }) {
+    return <div>{date.getDate()}</div>;
+}
+Object.assign(Page, {
+    getLayout: (page)=>page
+});
+Page.getInitialProps = _withSuperJSONInitProps(async ()=>({
+        date: new Date()
+    }), [
+    "smth"
+]);
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
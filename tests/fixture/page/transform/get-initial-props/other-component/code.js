@@ -0,0 +1,19 @@
+class Helper extends React.Component {
+  static getInitialProps() {
+    return {};
+  }
+}
+
+class Page extends React.Component {
+  static async getInitialProps() {
+    return { date: new Date() };
+  }
+
+  render() {
+    return <Helper date={this.props.date} />;
+  }
+}
+
+console.log(Helper.getInitialProps);
+
+export default Page;
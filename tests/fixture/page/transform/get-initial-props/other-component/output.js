@@ -0,0 +1,22 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONInitProps as _withSuperJSONInitProps } from "next-superjson-plugin/tools";
+class Helper extends React.Component {
+    static getInitialProps() {
+        return {};
+    }
+}
+class Page extends React.Component {
+    static getInitialProps = _withSuperJSONInitProps(async function() {
+        return {
+            date: new Date()
+        };
+    }, [
+        "smth"
+    ]);
+    render() {
+        return <Helper date={this.props.date}/>;
+    }
+}
+console.log(Helper.getInitialProps);
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
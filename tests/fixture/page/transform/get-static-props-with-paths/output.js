@@ -2,6 +2,9 @@ import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/t
 import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
 export const getStaticProps = _withSuperJSONProps(() => {}, ["smth"]);
 export const getStaticPaths = () => {};
-export default _withSuperJSONPage(() => {
+const NextSuperJSONPageComponent = () => {
   return <></>;
-});
+};
+const NextSuperJSONPage = _withSuperJSONPage(NextSuperJSONPageComponent);
+NextSuperJSONPage.displayName = "SuperJSON(Page)";
+export default NextSuperJSONPage;
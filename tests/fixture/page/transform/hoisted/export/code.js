@@ -0,0 +1,9 @@
+const loaders = { "/blog": getServerSideProps };
+
+export default function Page({ date }) {
+  return <p>{date.toISOString()}</p>;
+}
+
+export async function getServerSideProps() {
+  return { props: { date: new Date() } };
+}
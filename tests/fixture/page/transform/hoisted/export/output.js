@@ -0,0 +1,21 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+const loaders = {
+    "/blog": getServerSideProps
+};
+function Page({ date }) {
+    return <p>{date.toISOString()}</p>;
+}
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
+async function getServerSideProps() {
+    return {
+        props: {
+            date: new Date()
+        }
+    };
+}
+const _NEXT_SUPERJSON_SSG_PROPS = _withSuperJSONProps(getServerSideProps, [
+    "smth"
+]);
+export { _NEXT_SUPERJSON_SSG_PROPS as getServerSideProps };
@@ -0,0 +1,11 @@
+export { loadPost as getServerSideProps, loadPost };
+
+const cached = loadPost();
+
+export default function Page({ date }) {
+  return <p>{date.toISOString()}</p>;
+}
+
+async function loadPost() {
+  return { props: { date: new Date() } };
+}
@@ -0,0 +1,19 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+export { _NEXT_SUPERJSON_SSG_PROPS as getServerSideProps, loadPost };
+const cached = loadPost();
+function Page({ date }) {
+    return <p>{date.toISOString()}</p>;
+}
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
+async function loadPost() {
+    return {
+        props: {
+            date: new Date()
+        }
+    };
+}
+const _NEXT_SUPERJSON_SSG_PROPS = _withSuperJSONProps(loadPost, [
+    "smth"
+]);
@@ -0,0 +1,7 @@
+import gssp from "../lib/gssp";
+
+export { gssp as getServerSideProps };
+
+export default function Page({ date }) {
+  return <p>{date.toISOString()}</p>;
+}
@@ -5,4 +5,5 @@ const _NEXT_SUPERJSON_SSG_PROPS = _withSuperJSONProps(_NEXT_SUPERJSON_IMPORTED_P
     "smth"
 ]);
 export { _NEXT_SUPERJSON_SSG_PROPS as getServerSideProps };
-export default _withSuperJSONPage(Page);
\ No newline at end of file
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
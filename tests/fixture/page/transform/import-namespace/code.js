@@ -0,0 +1,7 @@
+import * as data from "../lib/data";
+
+export { data as getStaticProps };
+
+export default function Page({ date }) {
+  return <p>{date.toISOString()}</p>;
+}
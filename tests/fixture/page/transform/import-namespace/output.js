@@ -0,0 +1,12 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+import * as data from "../lib/data";
+const _NEXT_SUPERJSON_SSG_PROPS = _withSuperJSONProps(data, [
+    "smth"
+]);
+export { _NEXT_SUPERJSON_SSG_PROPS as getStaticProps };
+function Page({ date }) {
+    return <p>{date.toISOString()}</p>;
+}
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
@@ -4,4 +4,7 @@ let foo = 1;
 foo = 2;
 export { foo as getServerSideProps };
 foo = _withSuperJSONProps(() => {}, ["smth"]);
-export default _withSuperJSONPage(() => {});
+const NextSuperJSONPageComponent = () => {};
+const NextSuperJSONPage = _withSuperJSONPage(NextSuperJSONPageComponent);
+NextSuperJSONPage.displayName = "SuperJSON(Page)";
+export default NextSuperJSONPage;
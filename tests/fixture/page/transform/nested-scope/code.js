@@ -0,0 +1,16 @@
+let getServerSideProps = async () => ({ props: {} });
+
+function withLogging(handler) {
+  let getServerSideProps;
+  getServerSideProps = (ctx) => {
+    console.log(ctx.resolvedUrl);
+    return handler(ctx);
+  };
+  return getServerSideProps;
+}
+
+export { getServerSideProps };
+
+export default function Page() {
+  return <></>;
+}
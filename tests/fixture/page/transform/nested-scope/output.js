@@ -0,0 +1,21 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+let getServerSideProps = _withSuperJSONProps(async ()=>({
+        props: {}
+    }), [
+    "smth"
+]);
+function withLogging(handler) {
+    let getServerSideProps;
+    getServerSideProps = (ctx)=>{
+        console.log(ctx.resolvedUrl);
+        return handler(ctx);
+    };
+    return getServerSideProps;
+}
+export { getServerSideProps as getServerSideProps };
+function Page() {
+    return <></>;
+}
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
@@ -0,0 +1,7 @@
+"use superjson";
+
+export const getServerSideProps = () => {};
+
+export default function Page() {
+  return <></>;
+}
@@ -0,0 +1,5 @@
+export const getServerSideProps = async () => {}
+
+export default () => {
+  return <></>;
+}
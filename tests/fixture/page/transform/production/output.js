@@ -0,0 +1,9 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "next-superjson-plugin/tools";
+import { withSuperJSONProps as _withSuperJSONProps } from "next-superjson-plugin/tools";
+export const getServerSideProps = _withSuperJSONProps(async ()=>{});
+const NextSuperJSONPageComponent = ()=>{
+    return <></>;
+};
+const NextSuperJSONPage = _withSuperJSONPage(NextSuperJSONPageComponent);
+NextSuperJSONPage.displayName = "SuperJSON(Page)";
+export default NextSuperJSONPage;
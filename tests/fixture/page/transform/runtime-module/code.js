@@ -0,0 +1,5 @@
+export async function getServerSideProps() {}
+
+export default function Page() {
+  return <></>;
+}
@@ -0,0 +1,12 @@
+import { withSuperJSONPage as _withSuperJSONPage } from "@acme/superjson-runtime/server";
+import { withSuperJSONProps as _withSuperJSONProps } from "@acme/superjson-runtime/server";
+async function getServerSideProps() {}
+const _NEXT_SUPERJSON_SSG_PROPS = _withSuperJSONProps(getServerSideProps, [
+    "smth"
+]);
+export { _NEXT_SUPERJSON_SSG_PROPS as getServerSideProps };
+function Page() {
+    return <></>;
+}
+const NextSuperJSONPage = _withSuperJSONPage(Page);
+export default NextSuperJSONPage;
@@ -6,12 +6,19 @@ import * as React from "react";
 export default function SuperJSONComponent<P extends JSX.IntrinsicAttributes>({
   component,
   props,
+  displayName,
   children
 }: {
   component: React.ComponentType<P>;
   props: SuperJSONProps<P>;
+  // SuperJSON(Chart), from the element name in the server component
+  displayName?: string;
   children?: React.ReactNode;
 }) {
   const WithSuperJSON = withSuperJSONPage(component);
+  if (displayName) {
+    WithSuperJSON.displayName = displayName;
+  }
+
   return <WithSuperJSON {...props}>{children}</WithSuperJSON>;
 }
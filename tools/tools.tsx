@@ -9,9 +9,35 @@ export type SuperJSONProps<P> = P & {
   _superjson?: ReturnType<typeof SuperJSON.serialize>["meta"];
 };
 
+// passed by development builds only
+export type SuperJSONOptions = {
+  page?: string;
+};
+
+function pageName(options: SuperJSONOptions) {
+  return options.page ? `page "${options.page}"` : "page";
+}
+
+function warnMissingExcluded(
+  values: any,
+  exclude: string[],
+  options: SuperJSONOptions
+) {
+  exclude.forEach((key) => {
+    if (!(key in values)) {
+      console.warn(
+        `[next-superjson-plugin] excluded prop "${key}" is missing from the props of ${pageName(
+          options
+        )}`
+      );
+    }
+  });
+}
+
 export function withSuperJSONProps<P extends JSX.IntrinsicAttributes>(
   gssp: GetServerSideProps<P>,
-  exclude: string[] = []
+  exclude: string[] = [],
+  options?: SuperJSONOptions
 ): GetServerSideProps<SuperJSONProps<P>> {
   return async function withSuperJSON(...args) {
     const result = await gssp(...args);
@@ -24,6 +50,10 @@ export function withSuperJSONProps<P extends JSX.IntrinsicAttributes>(
       return result;
     }
 
+    if (options) {
+      warnMissingExcluded(result.props, exclude, options);
+    }
+
     const excludedPropValues = exclude.map((propKey) => {
       const value = (result.props as any)[propKey];
       delete (result.props as any)[propKey];
@@ -51,10 +81,18 @@ export function withSuperJSONProps<P extends JSX.IntrinsicAttributes>(
   };
 }
 
-export function withSuperJSONInitProps(gip: any, exclude: string[] = []): any {
+export function withSuperJSONInitProps(
+  gip: any,
+  exclude: string[] = [],
+  options?: SuperJSONOptions
+): any {
   return async function withSuperJSON(...args: any[]) {
     const result = await gip(...args);
 
+    if (options) {
+      warnMissingExcluded(result, exclude, options);
+    }
+
     const excludedPropValues = exclude.map((propKey) => {
       const value = (result as any)[propKey];
       delete (result as any)[propKey];
@@ -82,28 +120,69 @@ export function withSuperJSONInitProps(gip: any, exclude: string[] = []): any {
   };
 }
 
-export function deserializeProps<P>(serializedProps: SuperJSONProps<P>): P {
+export function deserializeProps<P>(
+  serializedProps: SuperJSONProps<P>,
+  options?: SuperJSONOptions
+): P {
   const { _superjson, ...props } = serializedProps;
-  return SuperJSON.deserialize({ json: props as any, meta: _superjson });
+
+  if (!options) {
+    return SuperJSON.deserialize({ json: props as any, meta: _superjson });
+  }
+
+  try {
+    return SuperJSON.deserialize({ json: props as any, meta: _superjson });
+  } catch (error) {
+    throw new Error(
+      `[next-superjson-plugin] failed to deserialize the props of ${pageName(
+        options
+      )}: ${error instanceof Error ? error.message : String(error)}`
+    );
+  }
 }
 
 export function withSuperJSONPage<P extends JSX.IntrinsicAttributes>(
-  Page: React.ComponentType<P>
+  Page: React.ComponentType<P>,
+  options?: SuperJSONOptions
 ): React.ComponentType<SuperJSONProps<P>> {
   function WithSuperJSON(serializedProps: SuperJSONProps<P>) {
-    return <Page {...deserializeProps<P>(serializedProps)} />;
+    return <Page {...deserializeProps<P>(serializedProps, options)} />;
   }
 
   hoistNonReactStatics(WithSuperJSON, Page);
 
+  // the plugin names anonymous pages after their route instead
+  WithSuperJSON.displayName = `SuperJSON(${
+    Page.displayName || Page.name || "Component"
+  })`;
+
   return WithSuperJSON;
 }
 
-export function serialize<P>(props: P): SuperJSONProps<P> {
-  const { json, meta: _superjson } = SuperJSON.serialize(props);
+export function serialize<P>(
+  props: P,
+  exclude: string[] = []
+): SuperJSONProps<P> {
+  const serializedProps = { ...props } as any;
+
+  const excludedPropValues = exclude.map((propKey) => {
+    const value = serializedProps[propKey];
+    delete serializedProps[propKey];
+    return value;
+  });
+
+  const { json, meta: _superjson } = SuperJSON.serialize(serializedProps);
+  const result = json as any;
+
+  exclude.forEach((key, index) => {
+    const excludedPropValue = excludedPropValues[index];
+    if (typeof excludedPropValue !== "undefined") {
+      result[key] = excludedPropValue;
+    }
+  });
 
   return {
-    ...(json as any),
+    ...result,
     _superjson,
   };
 }